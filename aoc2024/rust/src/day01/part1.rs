@@ -1,17 +1,15 @@
 use crate::data;
-use crate::util::quicksort;
+use crate::util::{ints_array, radix_sort};
 
 fn into_tuple(line: &str) -> (usize, usize) {
-    let mut pair = line.split("  ");
-    let left = pair.next().unwrap().trim().parse().unwrap();
-    let right = pair.next().unwrap().trim().parse().unwrap();
+    let [left, right] = ints_array(line).unwrap();
     (left, right)
 }
 
 pub fn solve() -> usize {
     let (mut left, mut right): (Vec<_>, Vec<_>) = data!().lines().map(into_tuple).unzip();
-    quicksort(&mut left);
-    quicksort(&mut right);
+    radix_sort(&mut left);
+    radix_sort(&mut right);
 
     let mut total = 0;
     let mut i = 0;
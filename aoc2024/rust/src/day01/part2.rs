@@ -1,33 +1,14 @@
-use std::collections::HashMap;
-
 use crate::data;
+use crate::util::{Counter, ints_array};
 
 fn into_tuple(line: &str) -> (usize, usize) {
-    let mut pair = line.split("  ");
-    let left = pair.next().unwrap().trim().parse().unwrap();
-    let right = pair.next().unwrap().trim().parse().unwrap();
+    let [left, right] = ints_array(line).unwrap();
     (left, right)
 }
 
-fn create_lookup(arr: &[usize]) -> HashMap<usize, usize> {
-    let mut map: HashMap<usize, usize> = HashMap::new();
-    arr.iter().for_each(|&n| *map.entry(n).or_insert(0) += 1);
-    map
-}
-
 pub fn solve() -> usize {
-    let (left, right): (Vec<_>, Vec<_>) = data!().lines().map(into_tuple).unzip();
-
-    let left = create_lookup(&left);
-    let right = create_lookup(&right);
-    let mut total = 0;
-
-    for (key, l_value) in &left {
-        let r_value = *right.get(key).unwrap_or(&0);
-        total += key * r_value * l_value
-    }
-
-    total
+    let (left, right): (Counter<_>, Counter<_>) = data!().lines().map(into_tuple).unzip();
+    left.iter().map(|(key, n)| key * n * right.get(key)).sum()
 }
 
 #[cfg(test)]
@@ -1,9 +1,8 @@
+use crate::util::ints;
 use crate::{Day, Runner, Solution};
 
 fn into_isize_vec(line: &str) -> Vec<isize> {
-    line.split_whitespace()
-        .map(|s| s.parse().expect("Cannot parse to usize"))
-        .collect()
+    ints(line).collect()
 }
 
 mod part1;
@@ -13,10 +12,10 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day02,
     p1: Runner {
         expected: 202,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 271,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
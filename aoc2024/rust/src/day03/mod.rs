@@ -7,10 +7,10 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day03,
     p1: Runner {
         expected: 173731097,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 93729253,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
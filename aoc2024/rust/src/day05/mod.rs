@@ -7,10 +7,10 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day05,
     p1: Runner {
         expected: 7198,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 4230,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
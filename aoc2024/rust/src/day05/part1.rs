@@ -1,10 +1,7 @@
-// use crate::util::StringMethods;
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
-
 use crate::data;
+use crate::util::{PartialOrder, StringMethods};
 
-type OrderMap = HashMap<usize, Vec<usize>>;
+type OrderMap = PartialOrder<usize>;
 
 /// will panic if values does not have an odd length
 fn get_middle<T: Copy>(values: &[T]) -> T {
@@ -20,43 +17,28 @@ fn parse_updates(s: &str) -> Vec<Vec<usize>> {
         .collect()
 }
 
-/// creates a hashmap of the order rules
+/// creates the partial order described by the `before|after` rules
 fn parse_order_rules(s: &str) -> OrderMap {
-    let mut map: OrderMap = HashMap::new();
-    let pairs: Vec<_> = s
-        .split_whitespace()
-        .map(|s| s.split('|').map(|s| s.parse().unwrap()).collect::<Vec<_>>())
-        .collect();
-
-    for pair in pairs {
-        let [key, value] = [pair[0], pair[1]];
-        if let Entry::Vacant(e) = map.entry(key) {
-            let value = vec![value];
-            e.insert(value);
-        } else {
-            map.get_mut(&key).unwrap().push(value);
-        }
-    }
-
-    map
+    s.split_whitespace()
+        .map(|s| {
+            let (before, after) = s.split_once('|').unwrap();
+            (before.parse().unwrap(), after.parse().unwrap())
+        })
+        .collect()
 }
 
 fn evaluate(update: &[usize], map: &OrderMap) -> usize {
-    for i in 0..update.len() - 1 {
-        match map.get(&update[i]) {
-            Some(mapping) if !mapping.contains(&update[i + 1]) => return 0,
-            None => return 0,
-            _ => (),
-        }
+    if map.is_sorted(update) {
+        get_middle(update)
+    } else {
+        0
     }
-    get_middle(update)
 }
 
 pub fn solve() -> usize {
-    let input: Vec<String> = data!().split("\n\n").map(String::from).collect();
-    let [rules_raw, order_raw] = [&input[0], &input[1]];
-    let map = parse_order_rules(rules_raw);
-    let updates = parse_updates(order_raw);
+    let mut blocks = data!().blocks();
+    let map = parse_order_rules(blocks.next().unwrap());
+    let updates = parse_updates(blocks.next().unwrap());
     updates.iter().map(|update| evaluate(update, &map)).sum()
 }
 
@@ -1,21 +1,11 @@
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
-
 use crate::data;
-use crate::util::StringMethods;
-
-type OrderMap = HashMap<usize, Vec<usize>>;
+use crate::util::{PartialOrder, StringMethods, select_nth_by};
 
-/// will panic if values does not have an odd length
-fn get_middle<T: Copy>(values: &[T]) -> T {
-    assert!(!values.len().is_multiple_of(2));
-    let mid_idx = (values.len() - 1) / 2;
-    values[mid_idx]
-}
+type OrderMap = PartialOrder<usize>;
 
 fn parse_updates(s: &str) -> Vec<Vec<usize>> {
     s.lines()
-        .filter(StringMethods::is_not_empty)
+        .filter(|l| l.is_not_empty())
         .map(|s| {
             s.split(',')
                 .map(|s| s.parse().expect("Cannot parse to usize"))
@@ -24,135 +14,38 @@ fn parse_updates(s: &str) -> Vec<Vec<usize>> {
         .collect()
 }
 
-/// creates a hashmap of the order rules
+/// creates the partial order described by the `before|after` rules
 fn parse_order_rules(s: &str) -> OrderMap {
-    let mut map: OrderMap = HashMap::new();
-    let pairs: Vec<_> = s
-        .split_whitespace()
-        .map(|s| s.split('|').map(|s| s.parse().unwrap()).collect::<Vec<_>>())
-        .collect();
-
-    for pair in pairs {
-        let [key, value] = [pair[0], pair[1]];
-        if let Entry::Vacant(e) = map.entry(key) {
-            let value = vec![value];
-            e.insert(value);
-        } else {
-            map.get_mut(&key).unwrap().push(value);
-        }
-    }
-
-    map
+    s.split_whitespace()
+        .map(|s| {
+            let (before, after) = s.split_once('|').unwrap();
+            (before.parse().unwrap(), after.parse().unwrap())
+        })
+        .collect()
 }
 
-fn is_in_order(update: &[usize], map: &OrderMap) -> bool {
-    for i in 0..update.len() - 1 {
-        if let Some(mapping) = map.get(&update[i]) {
-            if !mapping.contains(&update[i + 1]) {
-                return false;
-            }
-        } else {
-            return false;
-        }
-    }
-
-    true
-}
-
-// MAPPINGS
-// 29 -> 13
-// 47 -> 13, 53, 29, 61
-// 53 -> 13, 29
-// 61 -> 13, 29, 53
-// 75 -> 13, 29, 47, 53, 61
-// 97 -> 13, 29, 47, 53, 61, 75
-//
-// ALGO: Naive swap
-// ORDER 97, 13, 75, 29, 47
-// 1. 97 --> 13
-// 2. 13 -!> 75 order => 97, 75, 13, 29, 47
-// 3. 75 --> 13
-// 4. 13 -!> 29 order => 97, 75, 29, 13, 47
-// 5. 29 --> 13
-// 6. 13 -!> 47 order => 97, 75, 29, 47, 13
-// 7. 47 --> 13
-// 8. is_in_order(97, 75, 29, 47, 13)? false. rerun
-// 9. 97 --> 75
-//10. 75 --> 29
-//11. 29 -!> 47 order => 97, 75, 47, 29, 13
-//12. 47 --> 29
-//13. 29 --> 13
-// DONE. 97, 75, [47], 29, 13
-//
-// DONE. 61, [29], 13
-//
-// ORDER 61, 13, 29
-// 1. 61 --> 13
-// 2. 13 -!> 29 order => 61, 29, 13
-// 3. 29 --> 13
-// DONE. 61, [29], 13
-//
-//
-// ORDER 75, 97, 47, 61, 53
-// 1. 75 -!> 97 order => 97, 75, 47, 61, 53
-// 2. 97 --> 75
-// 3. 75 --> 47
-// 4. 47 --> 61
-// 5. 61 --> 53
-// DONE. 97, 75, [47], 61, 53
-
-// ALGO: Naive swap
-// ORDER 97, 13, 75, 29, 47
-// 1. 97 --> 13
-// 2. 13 -!> 75 order => 97, 75, 13, 29, 47
-// 3. 75 --> 13
-// 4. 13 -!> 29 order => 97, 75, 29, 13, 47
-// 5. 29 --> 13
-// 6. 13 -!> 47 order => 97, 75, 29, 47, 13
-// 7. 47 --> 13
-// 8. is_in_order(97, 75, 29, 47, 13)? false. rerun
-// 9. 97 --> 75
-//10. 75 --> 29
-//11. 29 -!> 47 order => 97, 75, 47, 29, 13
-//12. 47 --> 29
-//13. 29 --> 13
-// DONE. 97, 75, [47], 29, 13
-fn fix_order(update: &mut [usize], map: &OrderMap) -> Vec<usize> {
-    for i in 0..update.len() - 1 {
-        if let Some(mapping) = map.get(&update[i]) {
-            if !mapping.contains(&update[i + 1]) {
-                update.swap(i, i + 1);
-            }
-        } else {
-            update.swap(i, i + 1);
-        }
-    }
-
-    if is_in_order(update, map) {
-        return Vec::from(update);
-    }
-
-    fix_order(update, map) // my tail always be recursing
+/// The page that ends up in the middle once the update is put in order, found without sorting
+fn middle_page(update: &mut [usize], map: &OrderMap) -> usize {
+    let mid = update.len() / 2;
+    *select_nth_by(update, mid, |a, b| map.compare(a, b))
 }
 
 pub fn solve() -> usize {
-    let input: Vec<String> = data!().split("\n\n").map(String::from).collect();
-
-    let [rules_raw, order_raw] = [&input[0], &input[1]];
-    let map = parse_order_rules(rules_raw);
-    parse_updates(order_raw)
+    let mut blocks = data!().blocks();
+    let map = parse_order_rules(blocks.next().unwrap());
+    parse_updates(blocks.next().unwrap())
         .iter_mut()
-        .filter(|u| !is_in_order(u, &map))
-        .map(|u| get_middle(&fix_order(u, &map)))
+        .filter(|u| !map.is_sorted(u))
+        .map(|u| middle_page(u, &map))
         .sum()
 }
 
 #[cfg(test)]
 mod test {
-    use super::{OrderMap, fix_order, get_middle, parse_order_rules, parse_updates, solve};
+    use super::{OrderMap, middle_page, parse_order_rules, parse_updates, solve};
     use crate::example;
     use crate::util::Day::Day05;
-    use crate::util::validate;
+    use crate::util::{StringMethods, validate};
 
     #[test]
     fn test_solve() {
@@ -160,19 +53,11 @@ mod test {
     }
 
     #[test]
-    fn test_fix_order() {
+    fn test_middle_page() {
         let map = get_map();
-        let result = fix_order(&mut [75, 97, 47, 61, 53], &map);
-        let expected = vec![97, 75, 47, 61, 53];
-        assert_eq!(expected, result);
-
-        let result = fix_order(&mut [61, 13, 29], &map);
-        let expected = vec![61, 29, 13];
-        assert_eq!(expected, result);
-
-        let result = fix_order(&mut [97, 13, 75, 29, 47], &map);
-        let expected = vec![97, 75, 47, 29, 13];
-        assert_eq!(expected, result);
+        assert_eq!(47, middle_page(&mut [75, 97, 47, 61, 53], &map));
+        assert_eq!(29, middle_page(&mut [61, 13, 29], &map));
+        assert_eq!(47, middle_page(&mut [97, 13, 75, 29, 47], &map));
     }
 
     #[test]
@@ -197,23 +82,7 @@ mod test {
         let _ = parse_order_rules(raw);
     }
 
-    #[test]
-    fn test_get_middle() {
-        let input = &[1, 2, 3, 4, 5];
-        let result = get_middle(input);
-        assert_eq!(3, result);
-
-        let input = &[1, 2, 3, 4, 5, 6, 7];
-        let result = get_middle(input);
-        assert_eq!(4, result);
-    }
-
     fn get_map() -> OrderMap {
-        parse_order_rules(
-            &example!()
-                .split("\n\n")
-                .map(String::from)
-                .collect::<Vec<String>>()[0],
-        )
+        parse_order_rules(example!().blocks().next().unwrap())
     }
 }
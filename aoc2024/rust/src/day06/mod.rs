@@ -7,10 +7,10 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day06,
     p1: Runner {
         expected: 4559,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 1604,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::data;
-use crate::util::StringMethods;
+use crate::util::{Direction, StringMethods};
 
 #[allow(clippy::needless_range_loop)]
 /// returns (x, y) coordinates and direction of the guard's starting position
@@ -13,9 +13,7 @@ fn find_guard(grid: &[Vec<char>]) -> (usize, usize, Direction) {
                 continue;
             }
 
-            let direction = Direction::from(ch);
-            assert_ne!(direction, Direction::OffMap);
-            return (x, y, direction);
+            return (x, y, Direction::try_from(ch).unwrap());
         }
     }
     unreachable!() // we should always find a guard
@@ -27,32 +25,12 @@ struct Point {
     pub y: usize,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-    OffMap,
-}
-
-impl From<char> for Direction {
-    fn from(ch: char) -> Self {
-        match ch {
-            '^' => Self::Up,
-            '>' => Self::Right,
-            '<' => Self::Left,
-            'v' => Self::Down,
-            _ => unreachable!(),
-        }
-    }
-}
-
 #[derive(Clone, Debug, PartialEq)]
 struct Grid {
     pub grid: Vec<Vec<char>>,
     pub pos: Point,
-    pub direction: Direction,
+    /// `None` once the guard has walked off the map
+    pub direction: Option<Direction>,
 }
 
 impl Grid {
@@ -65,20 +43,20 @@ impl Grid {
         Self {
             grid,
             pos: Point { x, y },
-            direction,
+            direction: Some(direction),
         }
     }
 
     pub fn evaluate(&mut self) -> usize {
-        while self.direction != Direction::OffMap {
+        self.evaluate_with(|_| ())
+    }
+
+    /// Walks the guard off the map, calling `on_step` with the grid before every move
+    fn evaluate_with(&mut self, mut on_step: impl FnMut(&Self)) -> usize {
+        while let Some(direction) = self.direction {
+            on_step(self);
             self.grid[self.pos.y][self.pos.x] = 'X';
-            match self.direction {
-                Direction::Up => self.move_up(),
-                Direction::Down => self.move_down(),
-                Direction::Left => self.move_left(),
-                Direction::Right => self.move_right(),
-                Direction::OffMap => (),
-            }
+            self.advance(direction);
         }
 
         self.grid
@@ -88,39 +66,14 @@ impl Grid {
             .count()
     }
 
-    fn move_up(&mut self) {
-        self.direction = Direction::Up;
-        match self.grid[self.pos.y - 1][self.pos.x] {
-            '#' => self.direction = Direction::Right,  // rotate
-            'O' => self.direction = Direction::OffMap, // Done
-            _ => self.pos.y -= 1,                      // update
-        }
-    }
-
-    fn move_right(&mut self) {
-        self.direction = Direction::Right;
-        match self.grid[self.pos.y][self.pos.x + 1] {
-            '#' => self.direction = Direction::Down,   // rotate
-            'O' => self.direction = Direction::OffMap, // Done
-            _ => self.pos.x += 1,                      // update
-        }
-    }
-
-    fn move_down(&mut self) {
-        self.direction = Direction::Down;
-        match self.grid[self.pos.y + 1][self.pos.x] {
-            '#' => self.direction = Direction::Left,   // rotate
-            'O' => self.direction = Direction::OffMap, // Done
-            _ => self.pos.y += 1,                      // update
-        }
-    }
-
-    fn move_left(&mut self) {
-        self.direction = Direction::Left;
-        match self.grid[self.pos.y][self.pos.x - 1] {
-            '#' => self.direction = Direction::Up,     // rotate
-            'O' => self.direction = Direction::OffMap, // Done
-            _ => self.pos.x -= 1,                      // update
+    fn advance(&mut self, direction: Direction) {
+        let d = direction.to_point();
+        let x = self.pos.x.wrapping_add_signed(d.x);
+        let y = self.pos.y.wrapping_add_signed(d.y);
+        match self.grid[y][x] {
+            '#' => self.direction = Some(direction.turn_right()), // rotate
+            'O' => self.direction = None,                         // Done
+            _ => self.pos = Point { x, y },                       // update
         }
     }
 }
@@ -143,12 +96,25 @@ pub fn solve() -> usize {
 
 #[cfg(test)]
 mod test {
-    use super::solve;
+    use super::{Grid, solve};
+    use crate::example;
     use crate::util::Day::Day06;
-    use crate::util::validate;
+    use crate::util::{Recorder, validate};
 
     #[test]
     fn test_solve() {
         validate(solve, 4559, Day06);
     }
+
+    /// Replays the example in the terminal: `cargo test day06::part1::test::watch -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn watch() {
+        let mut recorder = Recorder::new();
+        Grid::new(example!().lines().collect()).evaluate_with(|g| {
+            let (x, y) = (g.pos.x as isize, g.pos.y as isize);
+            recorder.record_with(&g.grid.clone().into(), |frame| frame[(x, y)] = '^');
+        });
+        recorder.into_player().run();
+    }
 }
@@ -1,41 +1,23 @@
-use std::collections::HashSet;
-
 use rayon::prelude::*;
 
 use crate::data;
-use crate::util::StringMethods;
+use crate::util::{BitGrid, Direction, StringMethods};
 
-type PathSet = HashSet<(usize, usize)>;
 type Obs = (usize, usize);
-type Pos = (usize, usize, Dir);
+/// The guard's position and heading, `None` once it has walked off the map
+type Pos = (usize, usize, Option<Direction>);
 
 fn find_guard(grid: &[u8], width: usize, height: usize) -> Pos {
     for y in 0..height {
         for x in 0..width {
             if grid[y * width + x] == b'^' {
-                return (x, y, Dir::Up);
+                return (x, y, Some(Direction::North));
             }
         }
     }
     unreachable!("No guard found in grid") // we should always find a guard
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-enum Dir {
-    Up = 0,
-    Right = 1,
-    Down = 2,
-    Left = 3,
-    Off = 4,
-}
-
-const MOVES: [(isize, isize, Dir); 4] = [
-    (0, -1, Dir::Right),
-    (1, 0, Dir::Down),
-    (0, 1, Dir::Left),
-    (-1, 0, Dir::Up),
-];
-
 fn make_grid(data: &str) -> (Vec<u8>, usize, Pos) {
     let mut data: Vec<_> = data.lines().map(|s| s.to_string().pad(1, 'O')).collect();
     data.insert(0, "O".repeat(data[0].len()));
@@ -66,39 +48,42 @@ fn evaluate(args: (Vec<u8>, usize, Pos)) -> usize {
 
 #[inline(always)]
 fn next(state: Pos, grid: &[u8], obs: Obs, width: usize) -> Pos {
-    let (x, y, d) = state;
-    if d == Dir::Off {
-        return (x, y, Dir::Off);
-    }
+    let (x, y, Some(d)) = state else {
+        return state;
+    };
 
-    let (dx, dy, next_direction) = MOVES[d as usize];
-    let new_x = (x as isize + dx) as usize;
-    let new_y = (y as isize + dy) as usize;
+    let delta = d.to_point();
+    let new_x = x.wrapping_add_signed(delta.x);
+    let new_y = y.wrapping_add_signed(delta.y);
 
     if obs == (new_x, new_y) {
-        return (x, y, next_direction);
+        return (x, y, Some(d.turn_right()));
     }
 
     match grid[new_y * width + new_x] {
-        79 => (x, y, Dir::Off),
-        35 => (x, y, next_direction),
-        _ => (new_x, new_y, d),
+        79 => (x, y, None),
+        35 => (x, y, Some(d.turn_right())),
+        _ => (new_x, new_y, Some(d)),
     }
 }
 
-fn get_path(grid: &[u8], start_state: Pos, width: usize) -> PathSet {
-    let mut visited = HashSet::new();
+/// Every cell the guard visits apart from its start, where an obstacle cannot go
+fn get_path(grid: &[u8], start_state: Pos, width: usize) -> Vec<Obs> {
+    let mut visited = BitGrid::new(grid.len() / width, width);
     let (mut x, mut y, mut direction) = start_state;
 
-    while direction != Dir::Off {
-        visited.insert((x, y));
+    while direction.is_some() {
+        visited.insert((x as isize, y as isize));
         let (nx, ny, nd) = next((x, y, direction), grid, (10000, 10000), width);
         x = nx;
         y = ny;
         direction = nd;
     }
-    visited.remove(&(start_state.0, start_state.1));
+    visited.remove((start_state.0 as isize, start_state.1 as isize));
     visited
+        .iter()
+        .map(|p| (p.x as usize, p.y as usize))
+        .collect()
 }
 
 fn is_loop(start_state: Pos, grid: &[u8], obs: Obs, width: usize) -> bool {
@@ -107,12 +92,12 @@ fn is_loop(start_state: Pos, grid: &[u8], obs: Obs, width: usize) -> bool {
 
     loop {
         tort = next(tort, grid, obs, width);
-        if tort.2 == Dir::Off {
+        if tort.2.is_none() {
             return false;
         }
 
         hare = next(next(hare, grid, obs, width), grid, obs, width);
-        if hare.2 == Dir::Off {
+        if hare.2.is_none() {
             return false;
         }
 
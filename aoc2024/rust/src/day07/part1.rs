@@ -1,17 +1,12 @@
 use crate::data;
-use crate::util::StringMethods;
+use crate::util::ints;
 
 type Mapping = (usize, Vec<usize>);
 
 fn parse_line(l: &str) -> Mapping {
-    let (value, parts) = l.split_once(':').unwrap();
-    let value = value.trim().parse().unwrap();
-    let parts = parts
-        .split_whitespace()
-        .filter(StringMethods::is_not_empty)
-        .map(|s| s.parse().unwrap())
-        .collect();
-    (value, parts)
+    let mut values = ints(l);
+    let value = values.next().unwrap();
+    (value, values.collect())
 }
 
 fn parse(input: &str) -> Vec<Mapping> {
@@ -1,36 +1,20 @@
 use rayon::prelude::*;
 
 use crate::data;
-use crate::util::StringMethods;
+use crate::util::{concat, ints};
 
 type Mapping = (usize, Vec<usize>);
 
 fn parse_line(l: &str) -> Mapping {
-    let (value, parts) = l.split_once(':').unwrap();
-    let value = value.trim().parse().unwrap();
-    let parts = parts
-        .split_whitespace()
-        .filter(StringMethods::is_not_empty)
-        .map(|s| s.parse().unwrap())
-        .collect();
-    (value, parts)
+    let mut values = ints(l);
+    let value = values.next().unwrap();
+    (value, values.collect())
 }
 
 fn parse(input: &str) -> Vec<Mapping> {
     input.lines().map(parse_line).collect()
 }
 
-#[inline]
-fn concatenate_usize(root_value: usize, next_value: usize) -> usize {
-    let mut multiplier = 1;
-    let mut temp = next_value;
-    while temp > 0 {
-        multiplier *= 10;
-        temp /= 10;
-    }
-    root_value * multiplier + next_value
-}
-
 fn evaluate(root_value: usize, target: usize, idx: usize, values: &[usize]) -> bool {
     if values.len() == idx {
         return false;
@@ -38,7 +22,7 @@ fn evaluate(root_value: usize, target: usize, idx: usize, values: &[usize]) -> b
 
     let next_value = values[idx];
     let plus_value = root_value + next_value;
-    let concat_value = concatenate_usize(root_value, next_value);
+    let concat_value = concat(root_value, next_value);
     let mul_value = root_value * next_value;
     let is_target = plus_value == target || mul_value == target || concat_value == target;
     let all_values_used = values.len() - 1 == idx;
@@ -1,40 +1,25 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::data;
+use crate::util::{Point, group_by_key};
 
 type Row = Vec<char>;
-type Mapping = HashMap<char, Vec<(usize, usize)>>;
-type Point = (usize, usize);
+type Mapping = HashMap<char, Vec<Point>>;
 
 fn rotate_point(p: Point, pivot: Point, width: usize, height: usize) -> Option<Point> {
-    let (x, y) = p;
-    let (px, py) = pivot;
-    let x2 = px.checked_mul(2).and_then(|two_px| two_px.checked_sub(x))?;
-    let y2 = py.checked_mul(2).and_then(|two_py| two_py.checked_sub(y))?;
-
-    if x2 < width && y2 < height {
-        Some((x2, y2))
-    } else {
-        None
-    }
+    let r = p.reflect(pivot);
+    let inside = (0..width as isize).contains(&r.x) && (0..height as isize).contains(&r.y);
+    inside.then_some(r)
 }
 
-#[allow(clippy::needless_range_loop)]
 fn get_antennas(grid: &[Row]) -> Mapping {
-    let mut map: Mapping = HashMap::new();
-
-    for y in 0..grid.len() {
-        for x in 0..grid[0].len() {
-            let c = grid[y][x];
-            if c.is_alphanumeric() {
-                map.entry(c)
-                    .and_modify(|vec| vec.push((x, y)))
-                    .or_insert(vec![(x, y)]);
-            }
-        }
-    }
-
-    map
+    let antennas = grid.iter().enumerate().flat_map(|(y, row)| {
+        row.iter()
+            .enumerate()
+            .filter(|(_, c)| c.is_alphanumeric())
+            .map(move |(x, &c)| (c, Point::new(x as isize, y as isize)))
+    });
+    group_by_key(antennas)
 }
 
 fn evaluate(data: &str) -> usize {
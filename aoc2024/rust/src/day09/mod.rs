@@ -7,10 +7,10 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day09,
     p1: Runner {
         expected: 6448989155953,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 6476642796832,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
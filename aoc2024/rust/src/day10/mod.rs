@@ -26,10 +26,10 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day10,
     p1: Runner {
         expected: 517,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 1116,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
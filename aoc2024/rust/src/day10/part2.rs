@@ -1,6 +1,6 @@
 use super::{find_starting_points, make_grid};
-use crate::util::{Entry, Grid};
-use crate::{DIRECTIONS, data};
+use crate::data;
+use crate::util::{Direction, Entry, Grid, Point};
 
 fn neighbors(point: Entry<usize>, grid: &Grid<usize>) -> [Option<Entry<usize>>; 4] {
     let (x, y, v) = point;
@@ -11,7 +11,8 @@ fn neighbors(point: Entry<usize>, grid: &Grid<usize>) -> [Option<Entry<usize>>;
     let mut ns = [None; 4];
     let mut i = 0;
 
-    for (dx, dy) in DIRECTIONS {
+    for d in Direction::CARDINAL {
+        let Point { x: dx, y: dy } = d.to_point();
         let nx = x + dx;
         let ny = y + dy;
         if nx >= 0 && nx < w && ny >= 0 && ny < h && grid[(nx, ny)] == t {
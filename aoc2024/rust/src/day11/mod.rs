@@ -1,3 +1,4 @@
+use crate::util::{HashMemo, split_in_half};
 use crate::{Day, Runner, Solution};
 
 mod part1;
@@ -7,10 +8,34 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day11,
     p1: Runner {
         expected: 220999,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 261936432123724,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
+
+/// Number of stones a single stone turns into after `blinks` blinks
+fn blink(memo: &mut HashMemo<(usize, usize), usize>, (stone, blinks): (usize, usize)) -> usize {
+    if blinks == 0 {
+        return 1;
+    }
+
+    if stone == 0 {
+        memo.call((1, blinks - 1), blink)
+    } else if let Some((first, second)) = split_in_half(stone) {
+        memo.call((first, blinks - 1), blink) + memo.call((second, blinks - 1), blink)
+    } else {
+        memo.call((stone * 2024, blinks - 1), blink)
+    }
+}
+
+/// Total number of stones after `iterations` blinks
+fn evaluate(data: &str, iterations: usize) -> usize {
+    let mut memo = HashMemo::new();
+    data.split_whitespace()
+        .map(|s| s.parse::<usize>().unwrap())
+        .map(|stone| memo.call((stone, iterations), blink))
+        .sum()
+}
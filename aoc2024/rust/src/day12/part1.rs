@@ -1,4 +1,5 @@
-use crate::{DIRECTIONS, data};
+use crate::data;
+use crate::util::{Direction, Point};
 
 fn evaluate(input: &str) -> usize {
     let trimmed = input.trim();
@@ -24,7 +25,8 @@ fn flood(start: usize, g: &[u8], size: usize, seen: &mut [bool]) -> usize {
         area += 1;
 
         let (x, y) = (i % size, i / size);
-        for (dx, dy) in DIRECTIONS {
+        for d in Direction::CARDINAL {
+            let Point { x: dx, y: dy } = d.to_point();
             let nx = x as isize + dx;
             let ny = y as isize + dy;
             let nx_usize = nx as usize;
@@ -1,4 +1,4 @@
-use crate::DIRECTIONS;
+use crate::util::{Direction, Point};
 
 #[derive(Debug, Default)]
 struct Grid {
@@ -83,7 +83,8 @@ fn evaluate(data: &str) -> usize {
             while let Some((cx, cy)) = q.pop() {
                 area += 1;
                 sides += count_corners((cx, cy, v), &grid);
-                for &(dx, dy) in &DIRECTIONS {
+                for d in Direction::CARDINAL {
+                    let Point { x: dx, y: dy } = d.to_point();
                     let (nx, ny) = (cx as isize + dx, cy as isize + dy);
                     let (nx_usize, ny_usize) = (nx as usize, ny as usize);
                     if nx < 0 || nx_usize >= grid.width || ny < 0 || ny_usize >= grid.height {
@@ -1,3 +1,6 @@
+use std::str::FromStr;
+
+use crate::util::{FromFields, ParseError, StringMethods, cramer_2x2, scan};
 use crate::{Day, Runner, Solution};
 
 mod part1;
@@ -14,3 +17,76 @@ pub const SOLUTION: Solution = Solution {
         f: part2::solve,
     },
 };
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct PrizeTarget {
+    x: usize,
+    y: usize,
+}
+
+impl FromStr for PrizeTarget {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = crate::scan!("Prize: X={}, Y={}", s)?;
+        Ok(Self { x, y })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Button {
+    x: usize,
+    y: usize,
+    cost: usize,
+}
+
+impl FromStr for Button {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = scan("Button {}: X+{}, Y+{}", s)?;
+        let (label, x, y) = FromFields::from_fields(&fields)?;
+        let cost = match label {
+            'A' => 3,
+            'B' => 1,
+            _ => {
+                let err = ParseError::new(format!("unknown button {label:?}"));
+                return Err(err.at_column(fields[0].column));
+            }
+        };
+        Ok(Self { x, y, cost })
+    }
+}
+
+fn parse_machine(s: &str) -> Result<(Button, Button, PrizeTarget), ParseError> {
+    let lines: Vec<_> = s.trim().lines().collect();
+    let [a, b, prize] = lines[..] else {
+        let message = format!("expected 3 lines per machine, found {}", lines.len());
+        return Err(ParseError::new(message).at_line(1));
+    };
+
+    Ok((
+        a.parse().map_err(|e: ParseError| e.at_line(1))?,
+        b.parse().map_err(|e: ParseError| e.at_line(2))?,
+        prize.parse().map_err(|e: ParseError| e.at_line(3))?,
+    ))
+}
+
+fn parse_input(s: &str) -> Result<Vec<(Button, Button, PrizeTarget)>, ParseError> {
+    s.blocks()
+        .numbered()
+        .map(|(line, m)| parse_machine(m).map_err(|e| e.shift_lines(line - 1)))
+        .collect()
+}
+
+/// Solves `i * a + j * b = p` exactly with Cramer's rule.
+/// A prize is only won with a whole, non-negative number of presses of each button.
+fn get_cheapest((a, b, p): (Button, Button, PrizeTarget)) -> usize {
+    let m = [[a.x as i128, b.x as i128], [a.y as i128, b.y as i128]];
+    let presses = cramer_2x2(m, [p.x as i128, p.y as i128]).map(|s| s.map(|v| v.to_integer()));
+
+    match presses {
+        Some([Some(i), Some(j)]) if i >= 0 && j >= 0 => i as usize * a.cost + j as usize * b.cost,
+        _ => 0,
+    }
+}
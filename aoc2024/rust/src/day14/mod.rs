@@ -1,3 +1,6 @@
+use std::str::FromStr;
+
+use crate::util::{ParseError, Point, parse_lines};
 use crate::{Day, Runner, Solution};
 
 mod part1;
@@ -17,50 +20,33 @@ pub const SOLUTION: Solution = Solution {
 
 const WIDTH: usize = 101;
 const HEIGHT: usize = 103;
-const WIDTH_ISIZE: isize = 101;
-const HEIGHT_ISIZE: isize = 103;
+const BOUNDS: Point = Point::new(WIDTH as isize, HEIGHT as isize);
 const HALF_WIDTH: usize = WIDTH / 2;
 const HALF_HEIGHT: usize = HEIGHT / 2;
 
 #[derive(Debug, Clone, Copy)]
 struct Robot {
-    pos_x: isize,
-    pos_y: isize,
-    vel_x: isize,
-    vel_y: isize,
+    pos: Point,
+    vel: Point,
 }
 
-impl From<&str> for Robot {
-    fn from(s: &str) -> Self {
-        let s = s.strip_prefix("p=").unwrap();
-        let (pos_str, vel_str) = s.split_once(" ").unwrap();
-        let (px_str, py_str) = pos_str.split_once(',').unwrap();
-        let (vx_str, vy_str) = vel_str.strip_prefix("v=").unwrap().split_once(',').unwrap();
-        let pos_x = px_str.parse().unwrap();
-        let pos_y = py_str.parse().unwrap();
-        let vel_x = vx_str.parse().unwrap();
-        let vel_y = vy_str.parse().unwrap();
+impl FromStr for Robot {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (px, py, vx, vy) = crate::scan!("p={},{} v={},{}", s)?;
+        let pos = Point::new(px, py);
+        let vel = Point::new(vx, vy);
 
-        Robot {
-            pos_x,
-            pos_y,
-            vel_x,
-            vel_y,
-        }
+        Ok(Robot { pos, vel })
     }
 }
 
 fn step(bot: Robot, steps: usize) -> Robot {
-    let pos_x = (bot.pos_x + bot.vel_x * steps as isize).rem_euclid(WIDTH_ISIZE);
-    let pos_y = (bot.pos_y + bot.vel_y * steps as isize).rem_euclid(HEIGHT_ISIZE);
-
-    Robot {
-        pos_x,
-        pos_y,
-        ..bot
-    }
+    let pos = (bot.pos + bot.vel * steps as isize).rem_euclid(BOUNDS);
+    Robot { pos, ..bot }
 }
 
-fn parse_input(input: &str) -> Vec<Robot> {
-    input.trim().split('\n').map(Robot::from).collect()
+fn parse_input(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse_lines(input.trim())
 }
@@ -1,8 +1,9 @@
 use super::{HALF_HEIGHT, HALF_WIDTH, Robot, parse_input, step};
 use crate::data;
+use crate::util::ParseError;
 
 fn update_counts(robot: Robot) -> (usize, usize, usize, usize) {
-    let (x, y) = (robot.pos_x as usize, robot.pos_y as usize);
+    let (x, y) = (robot.pos.x as usize, robot.pos.y as usize);
     if x == HALF_WIDTH || y == HALF_HEIGHT {
         return (0, 0, 0, 0);
     }
@@ -15,39 +16,47 @@ fn update_counts(robot: Robot) -> (usize, usize, usize, usize) {
     }
 }
 
-pub fn evaluate(data: &str, steps: usize) -> usize {
-    parse_input(data)
+pub fn evaluate(data: &str, steps: usize) -> Result<usize, ParseError> {
+    let safety_factor = parse_input(data)?
         .into_iter()
         .map(|bot| update_counts(step(bot, steps)))
         .reduce(|(a, b, c, d), (tl, tr, bl, br)| (tl + a, tr + b, bl + c, br + d))
-        .map(|(tl, tr, bl, br)| tl * tr * bl * br)
-        .unwrap()
+        .map_or(0, |(tl, tr, bl, br)| tl * tr * bl * br);
+
+    Ok(safety_factor)
 }
 
-pub fn solve() -> usize {
+pub fn solve() -> Result<usize, ParseError> {
     evaluate(data!(), 100)
 }
 
 #[cfg(test)]
 mod test {
-    use super::solve;
+    use super::{parse_input, solve};
     use crate::day14::Robot;
     use crate::util::Day::Day14;
-    use crate::util::validate;
+    use crate::util::{Point, validate};
 
     #[test]
     fn test_solve() {
-        validate(solve, 230900224, Day14);
+        validate(solve, Ok(230900224), Day14);
     }
 
     #[test]
     fn make_robot() {
         let s = "p=24,28 v=-92,3";
-        let robot = Robot::from(s);
+        let robot: Robot = s.parse().unwrap();
+
+        assert_eq!(robot.pos, Point::new(24, 28));
+        assert_eq!(robot.vel, Point::new(-92, 3));
+    }
 
-        assert_eq!(robot.pos_x, 24);
-        assert_eq!(robot.pos_y, 28);
-        assert_eq!(robot.vel_x, -92);
-        assert_eq!(robot.vel_y, 3);
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("p=1,2 v=3,4\np=1,2 v=3,x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 11: cannot parse \"x\": invalid digit found in string"
+        );
     }
 }
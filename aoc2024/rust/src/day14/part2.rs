@@ -2,6 +2,7 @@ use rayon::prelude::*;
 
 use super::{HEIGHT, WIDTH, parse_input, step};
 use crate::data;
+use crate::util::ParseError;
 
 const RUN: usize = 11;
 
@@ -17,30 +18,66 @@ fn has_run(row: [bool; WIDTH]) -> bool {
     false
 }
 
-pub fn solve() -> usize {
-    let robots = parse_input(data!());
+pub fn solve() -> Result<usize, ParseError> {
+    let robots = parse_input(data!())?;
 
-    (1usize..(WIDTH * HEIGHT * 2))
+    let tree = (1usize..(WIDTH * HEIGHT * 2))
         .into_par_iter()
         .find_first(|num_steps| {
             let mut grid = [[false; WIDTH]; HEIGHT];
             for r in robots.iter().map(|r| step(*r, *num_steps)) {
-                grid[r.pos_y as usize][r.pos_x as usize] = true;
+                grid[r.pos.y as usize][r.pos.x as usize] = true;
             }
 
             grid.into_iter().any(has_run).then_some(step).is_some()
         })
-        .unwrap()
+        .unwrap();
+
+    Ok(tree)
 }
 
 #[cfg(test)]
 mod test {
-    use super::solve;
+    use super::{HEIGHT, WIDTH, parse_input, solve, step};
+    use crate::data;
     use crate::util::Day::Day14;
-    use crate::util::validate;
+    use crate::util::{Grid, ImageFormat, Recorder, validate};
 
     #[test]
     fn test_solve() {
-        validate(solve, 6532, Day14);
+        validate(solve, Ok(6532), Day14);
+    }
+
+    fn record(seconds: std::ops::Range<usize>) -> Recorder<bool> {
+        let robots = parse_input(data!()).unwrap();
+        let mut recorder = Recorder::new();
+
+        for steps in seconds {
+            let mut grid = Grid::make(HEIGHT, WIDTH, false);
+            for r in robots.iter().map(|r| step(*r, steps)) {
+                grid[r.pos] = true;
+            }
+            recorder.record(&grid);
+        }
+
+        recorder
+    }
+
+    /// Replays the seconds around the tree: `cargo test day14::part2::test::watch -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn watch() {
+        record(6520..6545).into_player().run();
+    }
+
+    /// Dumps the seconds around the tree as images into `target/day14`
+    #[test]
+    #[ignore]
+    fn export() {
+        let color = |&robot: &bool| if robot { (0, 200, 0) } else { (0, 0, 0) };
+        let paths = record(6520..6545)
+            .save_frames("target/day14", "robots", ImageFormat::Png, 4, color)
+            .unwrap();
+        assert_eq!(paths.len(), 25);
     }
 }
@@ -1,3 +1,4 @@
+use crate::util::{Direction, ParseError, Render, Rgb, StringMethods};
 use crate::{Day, Runner, Solution};
 
 mod part1;
@@ -15,26 +16,6 @@ pub const SOLUTION: Solution = Solution {
     },
 };
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl From<char> for Direction {
-    fn from(ch: char) -> Self {
-        match ch {
-            '^' => Self::Up,
-            '>' => Self::Right,
-            '<' => Self::Left,
-            'v' => Self::Down,
-            _ => unreachable!(),
-        }
-    }
-}
-
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Ord)]
 enum Kind {
     Robot, // '@'
@@ -43,26 +24,16 @@ enum Kind {
     Wall,  // '#'
 }
 
-impl From<char> for Kind {
-    fn from(ch: char) -> Self {
-        match ch {
-            '@' => Self::Robot,
-            'O' => Self::Box,
-            '.' => Self::Empty,
-            '#' => Self::Wall,
-            _ => unreachable!("Invalid character found"),
-        }
-    }
-}
+impl TryFrom<char> for Kind {
+    type Error = ParseError;
 
-impl From<&char> for Kind {
-    fn from(ch: &char) -> Self {
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
         match ch {
-            '@' => Self::Robot,
-            'O' => Self::Box,
-            '.' => Self::Empty,
-            '#' => Self::Wall,
-            _ => unreachable!("Invalid character found"),
+            '@' => Ok(Self::Robot),
+            'O' => Ok(Self::Box),
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Wall),
+            _ => Err(ParseError::new(format!("invalid map character {ch:?}"))),
         }
     }
 }
@@ -88,3 +59,41 @@ impl From<Kind> for char {
         }
     }
 }
+
+impl Render for Kind {
+    fn glyph(&self) -> char {
+        char::from(self)
+    }
+
+    fn color(&self) -> Option<Rgb> {
+        match self {
+            Kind::Robot => Some((255, 82, 0)),
+            Kind::Box => Some((127, 210, 0)),
+            Kind::Empty => None,
+            Kind::Wall => Some((100, 100, 100)),
+        }
+    }
+}
+
+/// Parses the robot's moves, which are wrapped over several lines
+fn parse_moves(s: &str) -> Result<Vec<Direction>, ParseError> {
+    s.lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars().enumerate().map(move |(x, ch)| {
+                Direction::try_from(ch).map_err(|e| e.at_line(y + 1).at_column(x + 1))
+            })
+        })
+        .collect()
+}
+
+/// Splits the input into the map and the moves, and the number of lines before the moves
+fn split_input(data: &str) -> Result<(&str, &str, usize), ParseError> {
+    let mut blocks = data.blocks().numbered();
+    match (blocks.next(), blocks.next()) {
+        (Some((_, grid)), Some((line, moves))) => Ok((grid, moves, line - 1)),
+        _ => Err(ParseError::new(
+            "expected a blank line between the map and the moves",
+        )),
+    }
+}
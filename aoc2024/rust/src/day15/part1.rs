@@ -1,7 +1,6 @@
-use std::fmt::Display;
-
-use super::{Direction, Kind};
+use super::{Kind, parse_moves, split_input};
 use crate::data;
+use crate::util::{Direction, ParseError};
 
 struct Grid {
     data: Vec<Vec<Kind>>,
@@ -10,37 +9,41 @@ struct Grid {
 }
 
 #[allow(clippy::needless_range_loop)]
-fn get_bot_pos(data: &[Vec<Kind>]) -> (usize, usize) {
+fn get_bot_pos(data: &[Vec<Kind>]) -> Result<(usize, usize), ParseError> {
     for y in 0..data.len() {
         for x in 0..data[0].len() {
             if data[y][x] == Kind::Robot {
-                return (x, y);
+                return Ok((x, y));
             }
         }
     }
 
-    unreachable!("Failed to find robot position!")
+    Err(ParseError::new("the map has no robot '@'"))
 }
 
 impl Grid {
-    pub fn new(input: &str) -> Self {
+    /// Parses the map without its outer wall, which the robot can never move into
+    pub fn new(input: &str) -> Result<Self, ParseError> {
         let lines: Vec<_> = input
             .lines()
-            .filter(|l| !l.chars().all(|c| c == '#'))
+            .enumerate()
+            .filter(|(_, l)| !l.chars().all(|c| c == '#'))
             .collect();
-        let data: Vec<_> = lines
+        let width = lines.first().map_or(0, |(_, l)| l.len().saturating_sub(2));
+        let data = lines
             .iter()
-            .map(|l| {
+            .map(|(y, l)| {
                 l.chars()
+                    .enumerate()
                     .skip(1)
-                    .take(lines[0].len() - 2)
-                    .map(Kind::from)
-                    .collect::<Vec<Kind>>()
+                    .take(width)
+                    .map(|(x, c)| Kind::try_from(c).map_err(|e| e.at_line(y + 1).at_column(x + 1)))
+                    .collect::<Result<Vec<Kind>, _>>()
             })
-            .collect();
-        let (bot_x, bot_y) = get_bot_pos(&data);
+            .collect::<Result<Vec<_>, _>>()?;
+        let (bot_x, bot_y) = get_bot_pos(&data)?;
 
-        Self { data, bot_x, bot_y }
+        Ok(Self { data, bot_x, bot_y })
     }
 
     fn move_bot(&mut self, x: usize, y: usize) {
@@ -108,22 +111,22 @@ impl Grid {
         let left_ok = self.bot_x != 0;
         let right_ok = self.bot_x != self.data[0].len() - 1;
         match dir {
-            Direction::Up if up_ok => match self.data[self.bot_y - 1][self.bot_x] {
+            Direction::North if up_ok => match self.data[self.bot_y - 1][self.bot_x] {
                 Kind::Box => self.move_up(self.bot_y - 1),
                 Kind::Empty => self.move_bot(self.bot_x, self.bot_y - 1),
                 _ => (),
             },
-            Direction::Down if down_ok => match self.data[self.bot_y + 1][self.bot_x] {
+            Direction::South if down_ok => match self.data[self.bot_y + 1][self.bot_x] {
                 Kind::Box => self.move_down(self.bot_y + 1),
                 Kind::Empty => self.move_bot(self.bot_x, self.bot_y + 1),
                 _ => (),
             },
-            Direction::Left if left_ok => match self.data[self.bot_y][self.bot_x - 1] {
+            Direction::West if left_ok => match self.data[self.bot_y][self.bot_x - 1] {
                 Kind::Box => self.move_left(self.bot_x - 1),
                 Kind::Empty => self.move_bot(self.bot_x - 1, self.bot_y),
                 _ => (),
             },
-            Direction::Right if right_ok => match self.data[self.bot_y][self.bot_x + 1] {
+            Direction::East if right_ok => match self.data[self.bot_y][self.bot_x + 1] {
                 Kind::Box => self.move_right(self.bot_x + 1),
                 Kind::Empty => self.move_bot(self.bot_x + 1, self.bot_y),
                 _ => (),
@@ -133,39 +136,16 @@ impl Grid {
     }
 }
 
-impl Display for Grid {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let pad: String = std::iter::repeat_n('#', self.data[0].len() + 2).collect();
-        writeln!(f, "{pad}")?;
-
-        for y in 0..self.data.len() {
-            let mut line = String::from('#');
-            for x in 0..self.data[y].len() {
-                line.push(char::from(self.data[y][x]));
-            }
-
-            line.push('#');
-            writeln!(f, "{line}")?;
-        }
+fn parse_input(input: &str) -> Result<(Grid, Vec<Direction>), ParseError> {
+    let (grid_part, dir_part, offset) = split_input(input)?;
+    let grid = Grid::new(grid_part)?;
+    let directions = parse_moves(dir_part).map_err(|e| e.shift_lines(offset))?;
 
-        writeln!(f, "{pad}")
-    }
+    Ok((grid, directions))
 }
 
-fn parse_input(input: &str) -> (Grid, Vec<Direction>) {
-    let (grid_part, dir_part) = input.split_once("\n\n").unwrap();
-    let grid = Grid::new(grid_part);
-    let directions = dir_part
-        .chars()
-        .filter(|c| !c.is_whitespace())
-        .map(Direction::from)
-        .collect();
-
-    (grid, directions)
-}
-
-fn evaluate(data: &str) -> usize {
-    let (mut grid, directions) = parse_input(data);
+fn evaluate(data: &str) -> Result<usize, ParseError> {
+    let (mut grid, directions) = parse_input(data)?;
     for d in directions {
         grid.next(d);
     }
@@ -180,33 +160,33 @@ fn evaluate(data: &str) -> usize {
         }
     }
 
-    result
+    Ok(result)
 }
 
-pub fn solve() -> usize {
+pub fn solve() -> Result<usize, ParseError> {
     evaluate(data!())
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Direction, Grid, Kind, evaluate, solve};
+    use super::{Direction, Grid, Kind, evaluate, parse_input, solve};
     use crate::example;
     use crate::util::Day::Day15;
     use crate::util::validate;
 
     #[test]
     fn test_solve() {
-        validate(solve, 1526673, Day15);
+        validate(solve, Ok(1526673), Day15);
     }
 
     #[test]
     fn test_evaluate() {
-        assert_eq!(evaluate(example!()), 10092)
+        assert_eq!(evaluate(example!()), Ok(10092))
     }
 
     #[test]
     fn test_evaluate_simple() {
-        assert_eq!(evaluate(SIMPLE), 2028)
+        assert_eq!(evaluate(SIMPLE), Ok(2028))
     }
 
     #[test]
@@ -221,15 +201,16 @@ mod test {
 #......#
 #......#
 ########",
-        );
+        )
+        .unwrap();
         assert_eq!((grid.bot_x, grid.bot_y), (3, 3));
         assert_eq!(grid.data[1][3], Kind::Box);
         assert_eq!(grid.data[2][3], Kind::Box);
-        grid.next(Direction::Up);
+        grid.next(Direction::North);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 2));
         assert_eq!(grid.data[0][3], Kind::Box);
         assert_eq!(grid.data[1][3], Kind::Box);
-        grid.next(Direction::Up);
+        grid.next(Direction::North);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 2));
         assert_eq!(grid.data[0][3], Kind::Box);
         assert_eq!(grid.data[1][3], Kind::Box);
@@ -247,16 +228,17 @@ mod test {
 #......#
 #......#
 ########",
-        );
+        )
+        .unwrap();
         assert_eq!((grid.bot_x, grid.bot_y), (3, 3));
         assert_eq!(grid.data[1][3], Kind::Box);
-        grid.next(Direction::Up);
+        grid.next(Direction::North);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 2));
         assert_eq!(grid.data[1][3], Kind::Box);
-        grid.next(Direction::Up);
+        grid.next(Direction::North);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 1));
         assert_eq!(grid.data[0][3], Kind::Box);
-        grid.next(Direction::Up);
+        grid.next(Direction::North);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 1));
         assert_eq!(grid.data[0][3], Kind::Box);
     }
@@ -273,16 +255,17 @@ mod test {
 #......#
 #......#
 ########",
-        );
+        )
+        .unwrap();
 
         assert_eq!((grid.bot_x, grid.bot_y), (3, 3));
-        grid.next(Direction::Up);
+        grid.next(Direction::North);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 2));
-        grid.next(Direction::Up);
+        grid.next(Direction::North);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 1));
-        grid.next(Direction::Up);
+        grid.next(Direction::North);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 0));
-        grid.next(Direction::Up);
+        grid.next(Direction::North);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 0));
     }
 
@@ -298,16 +281,17 @@ mod test {
 #...O..#
 #......#
 ########",
-        );
+        )
+        .unwrap();
 
         assert_eq!((grid.bot_x, grid.bot_y), (3, 2));
         assert_eq!(grid.data[4][3], Kind::Box);
         assert_eq!(grid.data[3][3], Kind::Box);
-        grid.next(Direction::Down);
+        grid.next(Direction::South);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 3));
         assert_eq!(grid.data[4][3], Kind::Box);
         assert_eq!(grid.data[5][3], Kind::Box);
-        grid.next(Direction::Down);
+        grid.next(Direction::South);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 3));
         assert_eq!(grid.data[4][3], Kind::Box);
         assert_eq!(grid.data[5][3], Kind::Box);
@@ -325,17 +309,18 @@ mod test {
 #...O..#
 #......#
 ########",
-        );
+        )
+        .unwrap();
 
         assert_eq!((grid.bot_x, grid.bot_y), (3, 2));
         assert_eq!(grid.data[4][3], Kind::Box);
-        grid.next(Direction::Down);
+        grid.next(Direction::South);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 3));
         assert_eq!(grid.data[4][3], Kind::Box);
-        grid.next(Direction::Down);
+        grid.next(Direction::South);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 4));
         assert_eq!(grid.data[5][3], Kind::Box);
-        grid.next(Direction::Down);
+        grid.next(Direction::South);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 4));
         assert_eq!(grid.data[5][3], Kind::Box);
     }
@@ -352,16 +337,17 @@ mod test {
 #......#
 #......#
 ########",
-        );
+        )
+        .unwrap();
 
         assert_eq!((grid.bot_x, grid.bot_y), (3, 2));
-        grid.next(Direction::Down);
+        grid.next(Direction::South);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 3));
-        grid.next(Direction::Down);
+        grid.next(Direction::South);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 4));
-        grid.next(Direction::Down);
+        grid.next(Direction::South);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 5));
-        grid.next(Direction::Down);
+        grid.next(Direction::South);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 5));
     }
 
@@ -372,23 +358,24 @@ mod test {
 ########
 #.O.O.@#
 ########",
-        );
+        )
+        .unwrap();
         assert_eq!(grid.data[0][1], Kind::Box);
         assert_eq!(grid.data[0][3], Kind::Box);
         assert_eq!((grid.bot_x, grid.bot_y), (5, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!(grid.data[0][1], Kind::Box);
         assert_eq!(grid.data[0][3], Kind::Box);
         assert_eq!((grid.bot_x, grid.bot_y), (4, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!(grid.data[0][1], Kind::Box);
         assert_eq!(grid.data[0][2], Kind::Box);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!(grid.data[0][0], Kind::Box);
         assert_eq!(grid.data[0][1], Kind::Box);
         assert_eq!((grid.bot_x, grid.bot_y), (2, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!(grid.data[0][0], Kind::Box);
         assert_eq!(grid.data[0][1], Kind::Box);
         assert_eq!((grid.bot_x, grid.bot_y), (2, 0));
@@ -401,19 +388,20 @@ mod test {
 ########
 #..O.@.#
 ########",
-        );
+        )
+        .unwrap();
         assert_eq!(grid.data[0][2], Kind::Box);
         assert_eq!((grid.bot_x, grid.bot_y), (4, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!(grid.data[0][2], Kind::Box);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!(grid.data[0][1], Kind::Box);
         assert_eq!((grid.bot_x, grid.bot_y), (2, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!(grid.data[0][0], Kind::Box);
         assert_eq!((grid.bot_x, grid.bot_y), (1, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!(grid.data[0][0], Kind::Box);
         assert_eq!((grid.bot_x, grid.bot_y), (1, 0));
     }
@@ -425,23 +413,24 @@ mod test {
 ########
 #@.O.O.#
 ########",
-        );
+        )
+        .unwrap();
         assert_eq!((grid.bot_x, grid.bot_y), (0, 0));
         assert_eq!(grid.data[0][2], Kind::Box);
         assert_eq!(grid.data[0][4], Kind::Box);
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (1, 0));
         assert_eq!(grid.data[0][2], Kind::Box);
         assert_eq!(grid.data[0][4], Kind::Box);
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (2, 0));
         assert_eq!(grid.data[0][3], Kind::Box);
         assert_eq!(grid.data[0][4], Kind::Box);
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 0));
         assert_eq!(grid.data[0][4], Kind::Box);
         assert_eq!(grid.data[0][5], Kind::Box);
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 0));
         assert_eq!(grid.data[0][4], Kind::Box);
         assert_eq!(grid.data[0][5], Kind::Box);
@@ -454,22 +443,23 @@ mod test {
 ########
 #@.O...#
 ########",
-        );
+        )
+        .unwrap();
         assert_eq!((grid.bot_x, grid.bot_y), (0, 0));
         assert_eq!(grid.data[0][2], Kind::Box);
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (1, 0));
         assert_eq!(grid.data[0][2], Kind::Box);
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (2, 0));
         assert_eq!(grid.data[0][3], Kind::Box);
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 0));
         assert_eq!(grid.data[0][4], Kind::Box);
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (4, 0));
         assert_eq!(grid.data[0][5], Kind::Box);
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (4, 0));
         assert_eq!(grid.data[0][5], Kind::Box);
     }
@@ -481,19 +471,20 @@ mod test {
 ########
 #@.....#
 ########",
-        );
+        )
+        .unwrap();
         assert_eq!((grid.bot_x, grid.bot_y), (0, 0));
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (1, 0));
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (2, 0));
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 0));
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (4, 0));
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (5, 0));
-        grid.next(Direction::Right);
+        grid.next(Direction::East);
         assert_eq!((grid.bot_x, grid.bot_y), (5, 0));
     }
 
@@ -504,17 +495,18 @@ mod test {
 ########
 #....@.#
 ########",
-        );
+        )
+        .unwrap();
         assert_eq!((grid.bot_x, grid.bot_y), (4, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!((grid.bot_x, grid.bot_y), (3, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!((grid.bot_x, grid.bot_y), (2, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!((grid.bot_x, grid.bot_y), (1, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!((grid.bot_x, grid.bot_y), (0, 0));
-        grid.next(Direction::Left);
+        grid.next(Direction::West);
         assert_eq!((grid.bot_x, grid.bot_y), (0, 0));
     }
 
@@ -523,26 +515,26 @@ mod test {
         let directions: Vec<_> = "<^^>>>vv<v>>v<<"
             .trim()
             .chars()
-            .map(Direction::from)
+            .map(|c| Direction::try_from(c).unwrap())
             .collect();
 
         assert_eq!(
             vec![
-                Direction::Left,
-                Direction::Up,
-                Direction::Up,
-                Direction::Right,
-                Direction::Right,
-                Direction::Right,
-                Direction::Down,
-                Direction::Down,
-                Direction::Left,
-                Direction::Down,
-                Direction::Right,
-                Direction::Right,
-                Direction::Down,
-                Direction::Left,
-                Direction::Left,
+                Direction::West,
+                Direction::North,
+                Direction::North,
+                Direction::East,
+                Direction::East,
+                Direction::East,
+                Direction::South,
+                Direction::South,
+                Direction::West,
+                Direction::South,
+                Direction::East,
+                Direction::East,
+                Direction::South,
+                Direction::West,
+                Direction::West,
             ],
             directions
         );
@@ -559,4 +551,19 @@ mod test {
 ########
 
 <^^>>>vv<v>>v<<";
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("####\n#@x#\n####\n\n<>").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: invalid map character 'x'"
+        );
+
+        let err = parse_input("####\n#.O#\n####\n\n<>").err().unwrap();
+        assert_eq!(err.to_string(), "the map has no robot '@'");
+
+        let err = parse_input("####\n#@.#\n####\n\n<>\n<?").err().unwrap();
+        assert_eq!(err.to_string(), "line 6, column 2: invalid direction '?'");
+    }
 }
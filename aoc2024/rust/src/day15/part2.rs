@@ -1,7 +1,8 @@
 use std::str::FromStr;
 
+use super::{parse_moves, split_input};
 use crate::data;
-use crate::util::{Entry, Grid, Point};
+use crate::util::{BitGrid, Direction, Entry, Grid, ParseError, Point, Render, Rgb};
 
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -13,9 +14,9 @@ enum Kind {
     Wall,
 }
 
-impl From<Kind> for char {
-    fn from(k: Kind) -> Self {
-        match k {
+impl Render for Kind {
+    fn glyph(&self) -> char {
+        match self {
             Kind::Bot => '@',
             Kind::Start => '[',
             Kind::End => ']',
@@ -23,88 +24,53 @@ impl From<Kind> for char {
             Kind::Wall => '#',
         }
     }
-}
-
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
 
-impl From<char> for Direction {
-    fn from(ch: char) -> Self {
-        match ch {
-            '^' => Direction::Up,
-            'v' => Direction::Down,
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            _ => unreachable!(),
+    fn color(&self) -> Option<Rgb> {
+        match self {
+            Kind::Bot => Some((255, 82, 0)),
+            Kind::Start | Kind::End => Some((127, 210, 0)),
+            Kind::Empty => None,
+            Kind::Wall => Some((100, 100, 100)),
         }
     }
 }
 
-#[derive(Default)]
+/// Buffers reused by every vertical push, so pushing does not allocate
 struct Scratch {
-    seen: Vec<u32>,
-    epoch: u32,
+    seen: BitGrid,
     moves: Vec<Entry<Kind>>,
-    w: usize,
-    h: usize,
 }
 
 impl Scratch {
     fn new(w: usize, h: usize) -> Self {
         Self {
-            seen: vec![0; w * h],
-            epoch: 1,
+            seen: BitGrid::new(h, w),
             moves: Vec::with_capacity(256),
-            w,
-            h,
         }
     }
-
-    #[inline]
-    fn idx(&self, x: isize, y: isize) -> usize {
-        (y * self.w as isize + x) as usize
-    }
-
-    #[inline]
-    fn mark(&mut self) -> u32 {
-        let e = self.epoch;
-        self.epoch = self.epoch.wrapping_add(1);
-        if self.epoch == 0 {
-            self.seen.fill(0);
-            self.epoch = 1;
-        }
-        e
-    }
 }
 
-fn collect_moves(grid: &Grid<Kind>, p: Point, dy: isize, s: &mut Scratch, tag: u32) -> bool {
+fn collect_moves(grid: &Grid<Kind>, p: Point, dy: isize, s: &mut Scratch) -> bool {
     let k = grid[p];
     if !matches!(k, Kind::Start | Kind::End) {
         return false;
     }
 
     let ox = if k == Kind::Start { p.x + 1 } else { p.x - 1 };
-    let i1 = s.idx(p.x, p.y);
-    let i2 = s.idx(ox, p.y);
-    if s.seen[i2] == tag {
+    if s.seen.contains((ox, p.y)) {
         return true;
     }
 
-    s.seen[i1] = tag;
-    s.seen[i2] = tag;
+    s.seen.insert(p);
+    s.seen.insert((ox, p.y));
 
     let ny = match p.y.checked_add(dy) {
-        Some(v) if v >= 0 && (v as usize) < s.h => v,
+        Some(v) if v >= 0 && (v as usize) < s.seen.height => v,
         _ => return false,
     };
 
     let ok1 = match grid[(p.x, ny)] {
-        Kind::Start | Kind::End => collect_moves(grid, Point::new(p.x, ny), dy, s, tag),
+        Kind::Start | Kind::End => collect_moves(grid, Point::new(p.x, ny), dy, s),
         Kind::Empty => true,
         _ => false,
     };
@@ -113,7 +79,7 @@ fn collect_moves(grid: &Grid<Kind>, p: Point, dy: isize, s: &mut Scratch, tag: u
     }
 
     let ok2 = match grid[(ox, ny)] {
-        Kind::Start | Kind::End => collect_moves(grid, Point::new(ox, ny), dy, s, tag),
+        Kind::Start | Kind::End => collect_moves(grid, Point::new(ox, ny), dy, s),
         Kind::Empty => true,
         _ => false,
     };
@@ -128,10 +94,10 @@ fn collect_moves(grid: &Grid<Kind>, p: Point, dy: isize, s: &mut Scratch, tag: u
 
 fn move_vert(grid: &mut Grid<Kind>, bot: &mut Point, p: Point, up: bool, s: &mut Scratch) {
     let dy = if up { -1 } else { 1 };
-    let tag = s.mark();
+    s.seen.clear();
     s.moves.clear();
 
-    if !collect_moves(grid, p, dy, s, tag) {
+    if !collect_moves(grid, p, dy, s) {
         return;
     }
 
@@ -144,18 +110,18 @@ fn move_vert(grid: &mut Grid<Kind>, bot: &mut Point, p: Point, up: bool, s: &mut
 
 fn step(b: &mut Point, g: &mut Grid<Kind>, d: Direction, s: &mut Scratch) {
     match d {
-        Direction::Left => match g[(b.x - 1, b.y)] {
+        Direction::West => match g[(b.x - 1, b.y)] {
             Kind::End => move_left(b.x - 3, b, g),
             Kind::Empty => b.x -= 1,
             _ => (),
         },
-        Direction::Right => match g[(b.x + 1, b.y)] {
+        Direction::East => match g[(b.x + 1, b.y)] {
             Kind::Start => move_right(b.x + 3, b, g),
             Kind::Empty => b.x += 1,
             _ => (),
         },
         vert => {
-            let up = vert == Direction::Up;
+            let up = vert == Direction::North;
             let dy = if up { b.y - 1 } else { b.y + 1 };
             let dp = Point::new(b.x, dy);
             match g[(b.x, dy)] {
@@ -167,7 +133,7 @@ fn step(b: &mut Point, g: &mut Grid<Kind>, d: Direction, s: &mut Scratch) {
     }
 }
 
-fn parse_grid(s: &str) -> Grid<Kind> {
+fn parse_grid(s: &str) -> Result<Grid<Kind>, ParseError> {
     let mut grid_string = String::with_capacity(s.len() * 2);
 
     const WALL: &str = "##";
@@ -175,44 +141,50 @@ fn parse_grid(s: &str) -> Grid<Kind> {
     const EMPTY: &str = "..";
     const GUARD: &str = "@.";
 
-    s.chars().for_each(|c| match c {
-        '#' => grid_string.push_str(WALL),
-        'O' => grid_string.push_str(BOX),
-        '.' => grid_string.push_str(EMPTY),
-        '@' => grid_string.push_str(GUARD),
-        '\n' => grid_string.push('\n'),
-        c => unreachable!("{c} is an invalid character!"),
-    });
-
-    Grid::from_str(&grid_string)
-        .unwrap()
-        .map_values(|v| match v {
-            '@' => Kind::Bot,
-            '[' => Kind::Start,
-            ']' => Kind::End,
-            '.' => Kind::Empty,
-            '#' => Kind::Wall,
-            _ => unreachable!(),
-        })
-}
+    for (y, line) in s.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                '#' => grid_string.push_str(WALL),
+                'O' => grid_string.push_str(BOX),
+                '.' => grid_string.push_str(EMPTY),
+                '@' => grid_string.push_str(GUARD),
+                c => {
+                    let message = format!("invalid map character {c:?}");
+                    return Err(ParseError::new(message).at_line(y + 1).at_column(x + 1));
+                }
+            }
+        }
+        grid_string.push('\n');
+    }
 
-fn parse_directions(s: &str) -> Vec<Direction> {
-    s.lines()
-        .flat_map(|l| l.chars())
-        .map(Direction::from)
-        .collect()
+    let grid = Grid::from_str(&grid_string).map_err(|e| ParseError::new(e.to_string()))?;
+    Ok(grid.map_values(|v| match v {
+        '@' => Kind::Bot,
+        '[' => Kind::Start,
+        ']' => Kind::End,
+        '.' => Kind::Empty,
+        '#' => Kind::Wall,
+        _ => unreachable!(),
+    }))
 }
 
-fn parse_input(data: &str) -> (Grid<Kind>, Vec<Direction>) {
-    data.trim()
-        .split_once("\n\n")
-        .map(|(g, d)| (parse_grid(g.trim()), parse_directions(d.trim())))
-        .unwrap()
+/// Returns the map, the robot's starting position and its moves
+fn parse_input(data: &str) -> Result<(Grid<Kind>, Point, Vec<Direction>), ParseError> {
+    let (g, d, offset) = split_input(data)?;
+    let mut grid = parse_grid(g)?;
+    let bot = get_bot(&mut grid)?;
+    let directions = parse_moves(d).map_err(|e| e.shift_lines(offset))?;
+
+    Ok((grid, bot, directions))
 }
 
-fn get_bot(grid: &mut Grid<Kind>) -> Point {
-    let (x, y, _) = grid.find_replace(|(_, _, k)| *k == Kind::Bot, Kind::Empty);
-    Point::new(x, y)
+/// Removes the robot from the map, returning where it was
+fn get_bot(grid: &mut Grid<Kind>) -> Result<Point, ParseError> {
+    let (x, y, _) = grid
+        .find(|(_, _, k)| *k == Kind::Bot)
+        .ok_or_else(|| ParseError::new("the map has no robot '@'"))?;
+    grid.set_unchecked((x, y), Kind::Empty);
+    Ok(Point::new(x, y))
 }
 
 fn move_right(x: isize, bot: &mut Point, grid: &mut Grid<Kind>) {
@@ -253,13 +225,22 @@ fn move_left(x: isize, bot: &mut Point, grid: &mut Grid<Kind>) {
     }
 }
 
-fn evaluate(data: &str) -> usize {
-    let (mut grid, directions) = parse_input(data);
-    let mut bot = get_bot(&mut grid);
+fn evaluate(data: &str) -> Result<usize, ParseError> {
+    evaluate_with(data, |_, _| ())
+}
+
+/// Runs the simulation, calling `on_step` with the grid and robot position after every move
+fn evaluate_with(
+    data: &str,
+    mut on_step: impl FnMut(&Grid<Kind>, Point),
+) -> Result<usize, ParseError> {
+    let (mut grid, mut bot, directions) = parse_input(data)?;
     let mut scratch = Scratch::new(grid.width, grid.height);
 
+    on_step(&grid, bot);
     for d in directions {
         step(&mut bot, &mut grid, d, &mut scratch);
+        on_step(&grid, bot);
     }
 
     // sum directly (avoid building a Vec)
@@ -271,26 +252,38 @@ fn evaluate(data: &str) -> usize {
             }
         }
     }
-    acc
+    Ok(acc)
 }
 
-pub fn solve() -> usize {
+pub fn solve() -> Result<usize, ParseError> {
     evaluate(data!())
 }
 
 #[cfg(test)]
 mod test {
-    use super::{evaluate, solve};
+    use super::{Kind, evaluate, evaluate_with, solve};
     use crate::example;
+    use crate::util::Recorder;
 
     #[test]
     fn test_solve() {
-        assert_eq!(solve(), 1535509);
+        assert_eq!(solve(), Ok(1535509));
     }
 
     #[test]
     fn test_evaluate() {
-        let result = evaluate(example!());
-        dbg!(result);
+        assert_eq!(evaluate(example!()), Ok(9021));
+    }
+
+    /// Replays the example in the terminal: `cargo test day15::part2::test::watch -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn watch() {
+        let mut recorder = Recorder::new();
+        evaluate_with(example!(), |grid, bot| {
+            recorder.record_with(grid, |frame| frame[bot] = Kind::Bot)
+        })
+        .unwrap();
+        recorder.into_player().run();
     }
 }
@@ -1,20 +1,19 @@
-use std::collections::VecDeque;
-
-use super::{AREA, START, State, dfs, index};
+use super::{AREA, Frontier, START, dfs, index};
 use crate::data;
+use crate::util::Direction;
 
 fn evaluate(data: &str) -> usize {
     let grid: Vec<_> = data.lines().flat_map(|l| l.chars()).collect();
     let mut seen = [[usize::MAX; 4]; AREA];
-    let mut first: VecDeque<State> = VecDeque::new();
-    let mut second: VecDeque<State> = VecDeque::new();
+    let (mut a, mut b) = (Frontier::new(), Frontier::new());
+    let (mut first, mut second) = (&mut a, &mut b);
     let mut lowest: usize = usize::MAX;
 
-    first.push_back((START, 0, 0));
-    seen[index(START)][0] = 0;
+    first.push((START, Direction::East, 0));
+    seen[index(START)][Direction::East.index()] = 0;
 
     while !first.is_empty() {
-        dfs(&mut first, &mut second, &mut lowest, &mut seen, &grid);
+        dfs(first, second, &mut lowest, &mut seen, &grid);
         std::mem::swap(&mut first, &mut second);
     }
 
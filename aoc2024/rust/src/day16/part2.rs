@@ -1,27 +1,26 @@
-use std::collections::VecDeque;
-
-use super::{AREA, BestPaths, DIRECTIONS, END, START, Seen, State, dfs, index};
+use super::{AREA, DIM, END, Frontier, START, Seen, dfs, index};
 use crate::data;
+use crate::util::{BitGrid, Direction};
 
-fn rev_dfs(todo: &mut VecDeque<State>, best_paths: &mut BestPaths, seen: &mut Seen) {
-    let Some((pos, dir, cost)) = todo.pop_front() else {
+fn rev_dfs(todo: &mut Frontier, best_paths: &mut BitGrid, seen: &mut Seen) {
+    let Some((pos, dir, cost)) = todo.pop() else {
         return;
     };
-    best_paths[index(pos)] = true;
+    best_paths.insert(pos);
 
     if pos == START {
         return rev_dfs(todo, best_paths, seen);
     }
 
-    let fwd = (pos - DIRECTIONS[dir], dir, cost - 1);
-    let left = (pos, (dir + 3) % 4, cost - 1000);
-    let right = (pos, (dir + 1) % 4, cost - 1000);
+    let fwd = (pos.step(dir.opposite()), dir, cost - 1);
+    let left = (pos, dir.turn_left(), cost - 1000);
+    let right = (pos, dir.turn_right(), cost - 1000);
 
     for (pos, dir, cost) in [fwd, left, right] {
         let idx = index(pos);
-        if cost == seen[idx][dir] {
-            todo.push_back((pos, dir, cost));
-            seen[idx][dir] = usize::MAX;
+        if cost == seen[idx][dir.index()] {
+            todo.push((pos, dir, cost));
+            seen[idx][dir.index()] = usize::MAX;
         }
     }
 
@@ -32,32 +31,30 @@ fn evaluate(data: &str) -> usize {
     let grid: Vec<_> = data.lines().flat_map(|l| l.chars()).collect();
 
     let mut seen = [[usize::MAX; 4]; AREA];
-    let mut best_paths: BestPaths = [false; AREA];
+    let mut best_paths = BitGrid::new(DIM, DIM);
 
-    let mut first: VecDeque<State> = VecDeque::new();
-    let mut second: VecDeque<State> = VecDeque::new();
+    let (mut a, mut b) = (Frontier::new(), Frontier::new());
+    let (mut first, mut second) = (&mut a, &mut b);
     let mut lowest: usize = usize::MAX;
 
-    first.push_back((START, 0, 0));
-    seen[index(START)][0] = 0;
+    first.push((START, Direction::East, 0));
+    seen[index(START)][Direction::East.index()] = 0;
 
     while !first.is_empty() {
-        dfs(&mut first, &mut second, &mut lowest, &mut seen, &grid);
+        dfs(first, second, &mut lowest, &mut seen, &grid);
         std::mem::swap(&mut first, &mut second);
     }
 
-    let mut todo = VecDeque::new();
-    for dir in [0, 1, 2, 3] {
-        if seen[index(END)][dir] == lowest {
-            todo.push_back((END, dir, lowest));
+    let mut todo = Frontier::new();
+    for dir in Direction::CARDINAL {
+        if seen[index(END)][dir.index()] == lowest {
+            todo.push((END, dir, lowest));
         }
     }
 
     rev_dfs(&mut todo, &mut best_paths, &mut seen);
 
-    best_paths
-        .into_iter()
-        .fold(0, |acc, bool| if bool { acc + 1 } else { acc })
+    best_paths.count()
 }
 
 pub fn solve() -> usize {
@@ -7,11 +7,11 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day17,
     p1: Runner {
         expected: 657457310,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 105875099912602,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
 
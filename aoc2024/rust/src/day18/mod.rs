@@ -1,7 +1,7 @@
 use std::fmt::Debug;
 
 use crate::util::dijkstra::Walkable;
-use crate::util::{Grid, Point};
+use crate::util::{Grid, Point, ints_array};
 use crate::{Day, Runner, Solution};
 
 mod part1;
@@ -41,9 +41,7 @@ fn get_points() -> Vec<Point> {
     crate::data!()
         .lines()
         .map(|line| {
-            let (x, y) = line.split_once(',').unwrap();
-            let x: isize = x.parse().unwrap();
-            let y: isize = y.parse().unwrap();
+            let [x, y] = ints_array(line).unwrap();
             Point::new(x, y)
         })
         .collect()
@@ -67,10 +65,10 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day18,
     p1: Runner {
         expected: 506,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 372,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
@@ -1,5 +1,6 @@
 use std::sync::LazyLock;
 
+use crate::util::{count_byte, line_count, lines, nth_line, split_trimmed};
 use crate::{Day, Runner, Solution};
 
 mod part1;
@@ -8,71 +9,13 @@ mod part2;
 static TRIE: LazyLock<Vec<Trie>> = LazyLock::new(build_trie);
 
 const INPUT: &str = crate::data!();
-const DESIGN_END_INDEX: usize = {
-    let mut design_start_index: usize = 0;
-    let bytes = INPUT.as_bytes();
+const PATTERNS_STR: &str = nth_line(INPUT, 0);
+const PATTERNS_COUNT: usize = count_byte(PATTERNS_STR, b',') + 1;
+const PATTERNS: [&str; PATTERNS_COUNT] = split_trimmed(PATTERNS_STR, b',');
 
-    loop {
-        if design_start_index >= bytes.len() {
-            break;
-        }
-        let byte = bytes[design_start_index];
-        if byte == b'\n' {
-            break;
-        }
-
-        design_start_index += 1;
-    }
-
-    design_start_index
-};
-
-const PATTERNS_STR: &str = &INPUT[..DESIGN_END_INDEX];
-const PATTERNS_COUNT: usize = crate::count_char!(PATTERNS_STR, b',') + 1;
-const PATTERNS: [&[u8]; PATTERNS_COUNT] = {
-    let bytes = PATTERNS_STR.as_bytes();
-    let text = PATTERNS_STR;
-
-    let mut patterns: [&[u8]; PATTERNS_COUNT] = [&[]; PATTERNS_COUNT];
-    let mut pattern_index: usize = 0;
-    let mut start: usize = 0;
-    let mut end: usize = start + 1;
-
-    while end < bytes.len() {
-        if pattern_index == PATTERNS_COUNT - 1 {
-            patterns[pattern_index] = &text.as_bytes()[start..];
-            break;
-        }
-
-        if bytes[end] == b',' {
-            patterns[pattern_index] = &text.as_bytes()[start..end];
-            start = end + 2;
-            end = start + 1;
-            pattern_index += 1;
-            continue;
-        }
-
-        end += 1;
-    }
-
-    patterns
-};
-
-const DESIGNS_STR: &str = INPUT[DESIGN_END_INDEX + 2..].trim_ascii(); // + 2 to skip newlines
-const DESIGNS_COUNT: usize = crate::count_char!(DESIGNS_STR, b'\n') + 1;
-const DESIGNS_STR_ARRAY: [&str; DESIGNS_COUNT] = crate::split_str!(const DESIGNS_STR, '\n');
-const DESIGNS: [&[u8]; DESIGNS_COUNT] = {
-    let mut designs: [&[u8]; DESIGNS_COUNT] = [&[]; DESIGNS_COUNT];
-    let mut i = 0;
-
-    while i < DESIGNS_COUNT {
-        designs[i] = DESIGNS_STR_ARRAY[i].as_bytes();
-
-        i += 1;
-    }
-
-    designs
-};
+const DESIGNS_STR: &str = INPUT.split_at(PATTERNS_STR.len()).1.trim_ascii();
+const DESIGNS_COUNT: usize = line_count(DESIGNS_STR);
+const DESIGNS: [&str; DESIGNS_COUNT] = lines(DESIGNS_STR);
 
 fn get_index(b: u8) -> usize {
     match b {
@@ -97,7 +40,7 @@ fn build_trie() -> Vec<Trie> {
     for pattern in PATTERNS {
         let mut ti = 0;
 
-        for &byte in pattern {
+        for byte in pattern.bytes() {
             let ch = get_index(byte);
             let mut ni = t[ti].n[ch];
 
@@ -153,10 +96,10 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day19,
     p1: Runner {
         expected: 287,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 571894474468161,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
@@ -3,7 +3,7 @@ use super::{DESIGNS, count};
 pub fn solve() -> usize {
     let mut result = 0;
     for design in DESIGNS {
-        if count(design) > 0 {
+        if count(design.as_bytes()) > 0 {
             result += 1;
         }
     }
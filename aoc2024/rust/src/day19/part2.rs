@@ -3,7 +3,7 @@ use super::{DESIGNS, count};
 pub fn solve() -> usize {
     let mut result = 0;
     for design in DESIGNS {
-        result += count(design)
+        result += count(design.as_bytes())
     }
 
     result
@@ -7,10 +7,10 @@ pub const SOLUTION: Solution = Solution {
     day: Day::Day20,
     p1: Runner {
         expected: 42,
-        f: part1::solve,
+        f: || Ok(part1::solve()),
     },
     p2: Runner {
         expected: 42,
-        f: part2::solve,
+        f: || Ok(part2::solve()),
     },
 };
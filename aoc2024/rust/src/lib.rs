@@ -1,4 +1,3 @@
-#![feature(const_index)]
 #![feature(const_trait_impl)]
 
 pub mod day01;
@@ -22,7 +21,5 @@ pub mod day18;
 pub mod day19;
 pub mod day20;
 
-pub const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
-
 mod util;
 pub use util::{Day, Part, Runner, Solution, colorize_time};
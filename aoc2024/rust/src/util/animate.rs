@@ -0,0 +1,333 @@
+use std::fmt::Debug;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+use anyhow::Result;
+
+use super::{Grid, ImageFormat, Render, Rgb, save_frames};
+
+const MIN_DELAY: Duration = Duration::from_millis(10);
+const MAX_DELAY: Duration = Duration::from_secs(2);
+const HELP: &str =
+    "[enter] play/pause  [n|b <count>] step  [g <frame>] seek  [+|-] speed  [q] quit";
+
+/// Collects `Grid` snapshots while a simulation runs so they can be replayed with a [Player]
+#[derive(Debug, Clone)]
+pub struct Recorder<T: Debug + Copy> {
+    frames: Vec<Grid<T>>,
+    every: usize,
+    calls: usize,
+}
+
+impl<T: Debug + Copy> Default for Recorder<T> {
+    fn default() -> Self {
+        Self {
+            frames: vec![],
+            every: 1,
+            calls: 0,
+        }
+    }
+}
+
+impl<T: Debug + Copy> Recorder<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a recorder that only keeps every `n`th snapshot it is given
+    pub fn every(n: usize) -> Self {
+        assert!(n > 0, "a recorder must keep at least every frame");
+        Self {
+            every: n,
+            ..Self::default()
+        }
+    }
+
+    /// Stores a copy of `grid` as the next frame
+    pub fn record(&mut self, grid: &Grid<T>) {
+        self.record_with(grid, |_| ());
+    }
+
+    /// Stores a copy of `grid` as the next frame after applying `f` to the copy.
+    /// Useful for drawing entities that the simulation tracks outside of the grid.
+    pub fn record_with(&mut self, grid: &Grid<T>, f: impl FnOnce(&mut Grid<T>)) {
+        self.calls += 1;
+        if !(self.calls - 1).is_multiple_of(self.every) {
+            return;
+        }
+
+        let mut frame = grid.clone();
+        f(&mut frame);
+        self.frames.push(frame);
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn frames(&self) -> &[Grid<T>] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<Grid<T>> {
+        self.frames
+    }
+
+    pub fn into_player(self) -> Player<T> {
+        Player::new(self.frames)
+    }
+
+    /// Writes every recorded frame to `dir` as a numbered image sequence
+    pub fn save_frames(
+        &self,
+        dir: impl AsRef<Path>,
+        prefix: &str,
+        format: ImageFormat,
+        scale: usize,
+        color: impl Fn(&T) -> Rgb,
+    ) -> Result<Vec<PathBuf>> {
+        save_frames(&self.frames, dir, prefix, format, scale, color)
+    }
+}
+
+/// A single instruction for the [Player], parsed from one line of terminal input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Toggle,
+    Step(isize),
+    Seek(usize),
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let cmd = parts.next().unwrap_or("");
+        let arg = parts.next().map(str::parse::<usize>).transpose()?;
+        let steps = |n: Option<usize>| match n {
+            Some(n) => {
+                isize::try_from(n).map_err(|_| anyhow::anyhow!("Step count {n} is too large"))
+            }
+            None => Ok(1),
+        };
+
+        let cmd = match (cmd, arg) {
+            ("" | "p", None) => Self::Toggle,
+            ("n", n) => Self::Step(steps(n)?),
+            ("b", n) => Self::Step(-steps(n)?),
+            ("g", Some(n)) => Self::Seek(n),
+            ("+", None) => Self::Faster,
+            ("-", None) => Self::Slower,
+            ("q", None) => Self::Quit,
+            _ => anyhow::bail!("Unknown command {s:?}"),
+        };
+
+        Ok(cmd)
+    }
+}
+
+/// Steps through recorded frames in the terminal
+#[derive(Debug, Clone)]
+pub struct Player<T: Debug + Copy> {
+    frames: Vec<Grid<T>>,
+    frame: usize,
+    playing: bool,
+    delay: Duration,
+}
+
+impl<T: Debug + Copy> Player<T> {
+    pub fn new(frames: Vec<Grid<T>>) -> Self {
+        Self {
+            frames,
+            frame: 0,
+            playing: false,
+            delay: Duration::from_millis(100),
+        }
+    }
+
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Sets the delay between frames while playing
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay.clamp(MIN_DELAY, MAX_DELAY);
+        self
+    }
+
+    fn last(&self) -> usize {
+        self.frames.len().saturating_sub(1)
+    }
+
+    /// Applies `cmd` to the player state.
+    /// Returns `false` once the player should stop.
+    pub fn apply(&mut self, cmd: Command) -> bool {
+        match cmd {
+            Command::Toggle => self.playing = !self.playing,
+            Command::Step(n) => {
+                self.playing = false;
+                self.frame = self.frame.saturating_add_signed(n).min(self.last());
+            }
+            Command::Seek(n) => self.frame = n.min(self.last()),
+            Command::Faster => self.delay = (self.delay / 2).max(MIN_DELAY),
+            Command::Slower => self.delay = (self.delay * 2).min(MAX_DELAY),
+            Command::Quit => return false,
+        }
+
+        true
+    }
+
+    /// Advances one frame while playing, pausing on the last frame
+    pub fn tick(&mut self) {
+        if !self.playing {
+            return;
+        }
+
+        if self.frame < self.last() {
+            self.frame += 1;
+        } else {
+            self.playing = false;
+        }
+    }
+}
+
+impl<T: Debug + Copy + Render> Player<T> {
+    fn draw(&self, out: &mut impl Write, note: &str) -> std::io::Result<()> {
+        let state = if self.playing { "playing" } else { "paused" };
+        write!(out, "\x1b[2J\x1b[H")?;
+        if let Some(grid) = self.frames.get(self.frame) {
+            write!(out, "{}", grid.render())?;
+        }
+        writeln!(
+            out,
+            "frame {}/{} | {state} | {:?} per frame",
+            self.frame,
+            self.last(),
+            self.delay
+        )?;
+        writeln!(out, "{HELP}")?;
+        writeln!(out, "{note}")?;
+        out.flush()
+    }
+
+    /// Plays the frames in the terminal, reading one command per line from stdin until `q` is
+    /// entered or stdin is closed
+    pub fn run(mut self) {
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in std::io::stdin().lock().lines().map_while(Result::ok) {
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut out = std::io::stdout().lock();
+        let mut note = String::new();
+        loop {
+            _ = self.draw(&mut out, &note);
+            note.clear();
+
+            let line = if self.playing {
+                match rx.recv_timeout(self.delay) {
+                    Ok(line) => line,
+                    Err(RecvTimeoutError::Timeout) => {
+                        self.tick();
+                        continue;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            } else {
+                match rx.recv() {
+                    Ok(line) => line,
+                    Err(_) => break,
+                }
+            };
+
+            match line.parse() {
+                Ok(cmd) if !self.apply(cmd) => break,
+                Ok(_) => (),
+                Err(e) => note = e.to_string(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{Command, Player, Recorder};
+    use crate::util::Grid;
+
+    fn frames(n: usize) -> Vec<Grid<usize>> {
+        (0..n).map(|i| Grid::make(1, 1, i)).collect()
+    }
+
+    #[test]
+    fn test_recorder_every() {
+        let mut recorder = Recorder::every(3);
+        for grid in frames(7) {
+            recorder.record(&grid);
+        }
+
+        let recorded: Vec<_> = recorder.frames().iter().map(|g| g[(0, 0)]).collect();
+        assert_eq!(recorded, [0, 3, 6]);
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!("".parse::<Command>().unwrap(), Command::Toggle);
+        assert_eq!("n".parse::<Command>().unwrap(), Command::Step(1));
+        assert_eq!("b 5".parse::<Command>().unwrap(), Command::Step(-5));
+        assert_eq!("g 12".parse::<Command>().unwrap(), Command::Seek(12));
+        assert_eq!("+".parse::<Command>().unwrap(), Command::Faster);
+        assert!("g".parse::<Command>().is_err());
+        assert!("x".parse::<Command>().is_err());
+        assert!(format!("n {}", usize::MAX).parse::<Command>().is_err());
+        assert_eq!(
+            format!("b {}", isize::MAX).parse::<Command>().unwrap(),
+            Command::Step(-isize::MAX)
+        );
+    }
+
+    #[test]
+    fn test_player_controls() {
+        let mut player = Player::new(frames(5));
+
+        player.apply(Command::Step(10));
+        assert_eq!(player.frame(), 4);
+        player.apply(Command::Step(-2));
+        assert_eq!(player.frame(), 2);
+        player.apply(Command::Seek(1));
+        assert_eq!(player.frame(), 1);
+
+        player.apply(Command::Toggle);
+        (0..10).for_each(|_| player.tick());
+        assert_eq!(player.frame(), 4);
+        assert!(!player.is_playing());
+
+        player.apply(Command::Faster);
+        assert_eq!(player.delay(), Duration::from_millis(50));
+        assert!(!player.apply(Command::Quit));
+    }
+}
@@ -3,7 +3,8 @@ use std::time::Duration;
 
 use comfy_table::Table;
 
-use crate::util::colorize_time;
+use crate::rgb;
+use crate::util::{ParseError, colorize_time};
 
 #[derive(Debug, Clone, Copy)]
 pub enum Part {
@@ -117,30 +118,33 @@ impl fmt::Display for Day {
 
 pub struct Runner {
     pub expected: usize,
-    pub f: fn() -> usize,
+    /// Solves the part, failing if the puzzle input cannot be parsed
+    pub f: fn() -> Result<usize, ParseError>,
 }
 
 impl Runner {
     /// Convenience wrapper to call `self.f`
-    pub fn run(&self) -> usize {
+    pub fn run(&self) -> Result<usize, ParseError> {
         (self.f)()
     }
 
-    /// Validates that `self.expected` == `self.f()`
-    pub fn validate(&self, day: Day, part: Part) {
+    /// Validates that `self.expected` == `self.f()`.
+    /// Input that cannot be parsed is returned as an error instead of panicking.
+    pub fn validate(&self, day: Day, part: Part) -> Result<(), ParseError> {
         assert_eq!(
             self.expected,
-            self.run(),
+            self.run()?,
             "\x1b[31m{day} {part} produced the wrong answer\x1b[0m",
         );
+        Ok(())
     }
 
     /// Validates that `self.expected` == `self.f()`
     /// returns the amount of time it took to run
-    pub fn timed_validate(&self, day: Day, part: Part) -> Duration {
+    pub fn timed_validate(&self, day: Day, part: Part) -> Result<Duration, ParseError> {
         let now = std::time::Instant::now();
-        self.validate(day, part);
-        now.elapsed()
+        self.validate(day, part)?;
+        Ok(now.elapsed())
     }
 }
 
@@ -151,18 +155,54 @@ pub struct Solution {
 }
 
 impl Solution {
-    /// Runs both parts and returns the sum of both parts runtime durations
+    /// Runs both parts and returns the sum of both parts runtime durations.
+    /// A part whose input cannot be parsed shows the error in the table instead of a time.
     pub fn solve(&self, table: &mut Table) -> Duration {
         let p1 = self.p1.timed_validate(self.day, Part::Part1);
         let p2 = self.p2.timed_validate(self.day, Part::Part2);
         let day: usize = self.day.into();
 
-        table.add_row(vec![
-            day.to_string(),
-            colorize_time(&p1),
-            colorize_time(&p2),
-        ]);
+        table.add_row(vec![day.to_string(), time_cell(&p1), time_cell(&p2)]);
+
+        [p1, p2].iter().flatten().sum()
+    }
+}
+
+fn time_cell(result: &Result<Duration, ParseError>) -> String {
+    match result {
+        Ok(time) => colorize_time(time),
+        Err(err) => rgb!(err, 255, 0, 0),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use comfy_table::Table;
+
+    use super::{Day, Part, Runner, Solution};
+    use crate::util::ParseError;
+
+    #[test]
+    fn test_parse_error_in_table() {
+        let solution = Solution {
+            day: Day::Day01,
+            p1: Runner {
+                expected: 3,
+                f: || Ok(3),
+            },
+            p2: Runner {
+                expected: 0,
+                f: || Err(ParseError::new("invalid direction '?'").at_line(2)),
+            },
+        };
+
+        let err = solution.p2.validate(Day::Day01, Part::Part2).unwrap_err();
+        assert_eq!(err.line, Some(2));
 
-        p1 + p2
+        let mut table = Table::new();
+        assert!(solution.solve(&mut table) < Duration::from_secs(1));
+        assert!(table.to_string().contains("invalid direction '?'"));
     }
 }
@@ -0,0 +1,223 @@
+use std::fmt::{Debug, Display};
+
+use super::{Grid, GridPoint, Point};
+
+const BITS: usize = u64::BITS as usize;
+
+/// A set of points in a `height` by `width` area, one bit per cell, for visited-tracking.
+///
+/// Takes the same points as [Grid] and lays cells out row by row the same way. Clearing,
+/// counting and the set operations work a word of 64 cells at a time.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    pub height: usize,
+    pub width: usize,
+}
+
+impl BitGrid {
+    /// Creates an empty set, taking `height` and `width` in the same order as `Grid::make`
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            words: vec![0; (height * width).div_ceil(BITS)],
+            height,
+            width,
+        }
+    }
+
+    /// Creates an empty set covering the same area as `grid`
+    pub fn for_grid<T: Debug + Copy>(grid: &Grid<T>) -> Self {
+        Self::new(grid.height, grid.width)
+    }
+
+    pub fn inside<P: GridPoint>(&self, p: P) -> bool {
+        let (x, y) = p.to_coordinate_pair();
+        (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y)
+    }
+
+    /// The word holding `p` and the mask of its bit
+    fn locate<P: GridPoint>(&self, p: P) -> Option<(usize, u64)> {
+        let (x, y) = p.to_coordinate_pair();
+        let i = self
+            .inside(p)
+            .then(|| y as usize * self.width + x as usize)?;
+        Some((i / BITS, 1 << (i % BITS)))
+    }
+
+    /// Returns false for points outside the area
+    pub fn contains<P: GridPoint>(&self, p: P) -> bool {
+        self.locate(p)
+            .is_some_and(|(word, mask)| self.words[word] & mask != 0)
+    }
+
+    /// Adds `p`, returning true if it was not in the set yet. Panics if `p` is outside the area.
+    pub fn insert<P: GridPoint>(&mut self, p: P) -> bool {
+        let (word, mask) = self
+            .locate(p)
+            .unwrap_or_else(|| panic!("{p:?} is outside the grid"));
+        let added = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        added
+    }
+
+    /// Removes `p`, returning true if it was in the set
+    pub fn remove<P: GridPoint>(&mut self, p: P) -> bool {
+        let Some((word, mask)) = self.locate(p) else {
+            return false;
+        };
+        let removed = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        removed
+    }
+
+    /// Empties the set, keeping its allocation so it can be reused
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Number of points in the set
+    pub fn count(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    fn assert_same_size(&self, other: &Self) {
+        assert!(
+            (self.height, self.width) == (other.height, other.width),
+            "bit grids must have the same dimensions"
+        );
+    }
+
+    /// Adds every point of `other`
+    pub fn union_with(&mut self, other: &Self) {
+        self.assert_same_size(other);
+        self.words
+            .iter_mut()
+            .zip(&other.words)
+            .for_each(|(a, b)| *a |= b);
+    }
+
+    /// Keeps only the points that are also in `other`
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.assert_same_size(other);
+        self.words
+            .iter_mut()
+            .zip(&other.words)
+            .for_each(|(a, b)| *a &= b);
+    }
+
+    /// Removes every point of `other`
+    pub fn difference_with(&mut self, other: &Self) {
+        self.assert_same_size(other);
+        self.words
+            .iter_mut()
+            .zip(&other.words)
+            .for_each(|(a, b)| *a &= !b);
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.assert_same_size(other);
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(a, b)| a & !b == 0)
+    }
+
+    /// Iterates over the points in the set, row by row
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    let cell = i * BITS + bit;
+                    Point::new((cell % self.width) as isize, (cell / self.width) as isize)
+                })
+            })
+        })
+    }
+}
+
+/// Draws the set as `#` for points in it and `.` for the rest
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height as isize {
+            let row: String = (0..self.width as isize)
+                .map(|x| if self.contains((x, y)) { '#' } else { '.' })
+                .collect();
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BitGrid {}x{}\n{self}", self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BitGrid;
+    use crate::util::{Grid, Point};
+
+    #[test]
+    fn test_insert_remove() {
+        let mut seen = BitGrid::new(3, 30);
+        assert!(seen.insert(Point::new(29, 2)));
+        assert!(!seen.insert((29, 2)));
+        assert!(seen.insert((5, 2)));
+        assert!(seen.insert((0, 0)));
+
+        assert!(seen.contains((5, 2)));
+        assert!(!seen.contains((6, 2)));
+        assert!(!seen.contains((30, 0)));
+        assert!(!seen.contains((-1, 0)));
+        assert_eq!(seen.count(), 3);
+        assert_eq!(
+            seen.iter().collect::<Vec<_>>(),
+            [Point::new(0, 0), Point::new(5, 2), Point::new(29, 2)]
+        );
+
+        assert!(seen.remove((5, 2)));
+        assert!(!seen.remove((5, 2)));
+        assert!(!seen.remove((99, 99)));
+        seen.clear();
+        assert!(seen.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let grid: Grid<u8> = Grid::make(2, 3, 0);
+        let mut a = BitGrid::for_grid(&grid);
+        let mut b = BitGrid::for_grid(&grid);
+        a.insert((0, 0));
+        a.insert((1, 1));
+        b.insert((1, 1));
+        b.insert((2, 0));
+
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(union.to_string(), "#.#\n.#.\n");
+
+        let mut both = a.clone();
+        both.intersect_with(&b);
+        assert_eq!(both.iter().collect::<Vec<_>>(), [Point::new(1, 1)]);
+        assert!(both.is_subset(&a) && both.is_subset(&b));
+        assert!(!a.is_subset(&b));
+
+        a.difference_with(&b);
+        assert_eq!(a.to_string(), "#..\n...\n");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_outside() {
+        BitGrid::new(2, 2).insert((2, 0));
+    }
+}
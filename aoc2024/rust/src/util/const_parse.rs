@@ -0,0 +1,387 @@
+//! `const fn` helpers for preprocessing puzzle input at compile time.
+//!
+//! Array sizes have to be known up front, so the usual pattern is to count first and then split:
+//!
+//! ```ignore
+//! const INPUT: &str = crate::data!();
+//! const LINES: [&str; line_count(INPUT)] = lines(INPUT);
+//! ```
+
+use super::Point;
+
+/// Returns the part of `s` between the byte offsets `start` and `end`
+const fn substr(s: &str, start: usize, end: usize) -> &str {
+    let (head, _) = s.as_bytes().split_at(end);
+    let (_, mid) = head.split_at(start);
+    match std::str::from_utf8(mid) {
+        Ok(mid) => mid,
+        Err(_) => panic!("split inside a multi-byte character"),
+    }
+}
+
+/// Drops the `\r` a CRLF line ending leaves behind
+const fn trim_cr(s: &str) -> &str {
+    match s.as_bytes().last() {
+        Some(b'\r') => substr(s, 0, s.len() - 1),
+        _ => s,
+    }
+}
+
+/// Counts the occurrences of `b` in `s`
+pub const fn count_byte(s: &str, b: u8) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Returns the offset of the first `b` at or after `from`
+pub const fn find_byte(s: &str, b: u8, from: usize) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut i = from;
+    while i < bytes.len() {
+        if bytes[i] == b {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Number of lines in `s`, where a trailing newline does not start another line
+pub const fn line_count(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    match bytes.last() {
+        None => 0,
+        Some(b'\n') => count_byte(s, b'\n'),
+        Some(_) => count_byte(s, b'\n') + 1,
+    }
+}
+
+/// Splits `s` on every `delim`. `N` must be the number of delimiters plus one.
+pub const fn split<const N: usize>(s: &str, delim: u8) -> [&str; N] {
+    assert!(
+        count_byte(s, delim) + 1 == N,
+        "N must be the number of pieces"
+    );
+
+    let mut pieces = [""; N];
+    let mut start = 0;
+    let mut i = 0;
+    while i < N {
+        let end = match find_byte(s, delim, start) {
+            Some(end) => end,
+            None => s.len(),
+        };
+        pieces[i] = substr(s, start, end);
+        start = end + 1;
+        i += 1;
+    }
+    pieces
+}
+
+/// Like [split], with ASCII whitespace trimmed from every piece, e.g. for `"r, wr, b"`
+pub const fn split_trimmed<const N: usize>(s: &str, delim: u8) -> [&str; N] {
+    let mut pieces = split::<N>(s, delim);
+    let mut i = 0;
+    while i < N {
+        pieces[i] = pieces[i].trim_ascii();
+        i += 1;
+    }
+    pieces
+}
+
+/// Splits `s` into lines, accepting `\r\n` line endings. `N` must be [line_count] of `s`.
+pub const fn lines<const N: usize>(s: &str) -> [&str; N] {
+    let s = match s.as_bytes().last() {
+        Some(b'\n') => substr(s, 0, s.len() - 1),
+        _ => s,
+    };
+    if N == 0 {
+        return [""; N];
+    }
+
+    let mut lines = split::<N>(s, b'\n');
+    let mut i = 0;
+    while i < N {
+        lines[i] = trim_cr(lines[i]);
+        i += 1;
+    }
+    lines
+}
+
+/// Returns line `n` of `s`, without its `\n` or `\r\n`
+pub const fn nth_line(s: &str, n: usize) -> &str {
+    let mut start = 0;
+    let mut i = 0;
+    while i < n {
+        start = match find_byte(s, b'\n', start) {
+            Some(end) => end + 1,
+            None => panic!("not enough lines"),
+        };
+        i += 1;
+    }
+
+    let line = match find_byte(s, b'\n', start) {
+        Some(end) => substr(s, start, end),
+        None => substr(s, start, s.len()),
+    };
+    trim_cr(line)
+}
+
+/// Parses an optionally signed decimal integer, panicking on anything else
+pub const fn parse_int(s: &str) -> isize {
+    let bytes = s.as_bytes();
+    let (negative, start) = match bytes.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    assert!(start < bytes.len(), "expected an integer");
+
+    let mut value: isize = 0;
+    let mut i = start;
+    while i < bytes.len() {
+        assert!(bytes[i].is_ascii_digit(), "expected an integer");
+        value = value * 10 + (bytes[i] - b'0') as isize;
+        i += 1;
+    }
+
+    if negative { -value } else { value }
+}
+
+/// Parses an unsigned decimal integer, panicking on anything else
+pub const fn parse_uint(s: &str) -> usize {
+    assert!(!s.is_empty(), "expected an integer");
+    let bytes = s.as_bytes();
+    let mut value = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(bytes[i].is_ascii_digit(), "expected an integer");
+        value = value * 10 + (bytes[i] - b'0') as usize;
+        i += 1;
+    }
+    value
+}
+
+/// Returns true if a number starts at offset `i`, matching `-?[0-9]+` like the runtime parsers
+const fn number_starts_at(bytes: &[u8], i: usize) -> bool {
+    let b = bytes[i];
+    if b == b'-' {
+        return i + 1 < bytes.len() && bytes[i + 1].is_ascii_digit();
+    }
+    let continues = i > 0 && (bytes[i - 1].is_ascii_digit() || bytes[i - 1] == b'-');
+    b.is_ascii_digit() && !continues
+}
+
+/// Counts the integers embedded in `s`, such as the four in `"p=0,4 v=3,-3"`
+pub const fn count_ints(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if number_starts_at(bytes, i) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Extracts every integer embedded in `s`. `N` must be [count_ints] of `s`.
+pub const fn const_ints<const N: usize>(s: &str) -> [isize; N] {
+    let bytes = s.as_bytes();
+    let mut values = [0; N];
+    let mut n = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if number_starts_at(bytes, i) {
+            let mut end = i + 1;
+            while end < bytes.len() && bytes[end].is_ascii_digit() {
+                end += 1;
+            }
+            values[n] = parse_int(substr(s, i, end));
+            n += 1;
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    assert!(n == N, "N must be the number of integers");
+    values
+}
+
+/// Width and height of a rectangular grid, ignoring a trailing newline
+pub const fn grid_dims(s: &str) -> (usize, usize) {
+    let width = match find_byte(s, b'\n', 0) {
+        Some(w) => w,
+        None => s.len(),
+    };
+    (width, line_count(s))
+}
+
+/// Returns the position of the first `marker` in a grid, such as the `S` of a maze
+pub const fn find_marker(s: &str, marker: u8) -> Option<Point> {
+    let bytes = s.as_bytes();
+    let (mut x, mut y) = (0, 0);
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b if b == marker => return Some(Point::new(x, y)),
+            b'\n' => {
+                x = 0;
+                y += 1;
+            }
+            _ => x += 1,
+        }
+        i += 1;
+    }
+    None
+}
+
+/// A `W` by `H` grid of bytes built at compile time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByteGrid<const W: usize, const H: usize> {
+    pub cells: [[u8; W]; H],
+}
+
+impl<const W: usize, const H: usize> ByteGrid<W, H> {
+    /// Parses a grid whose dimensions match [grid_dims], panicking on ragged rows
+    pub const fn parse(s: &str) -> Self {
+        let mut cells = [[0; W]; H];
+        let bytes = s.as_bytes();
+        let mut y = 0;
+        while y < H {
+            let row = (W + 1) * y;
+            assert!(
+                row + W <= bytes.len() && (row + W == bytes.len() || bytes[row + W] == b'\n'),
+                "rows must all have the same width"
+            );
+
+            let mut x = 0;
+            while x < W {
+                cells[y][x] = bytes[row + x];
+                x += 1;
+            }
+            y += 1;
+        }
+        Self { cells }
+    }
+
+    pub const fn width(&self) -> usize {
+        W
+    }
+
+    pub const fn height(&self) -> usize {
+        H
+    }
+
+    /// Returns `None` if `p` is outside the grid
+    pub const fn get(&self, p: Point) -> Option<u8> {
+        if p.x < 0 || p.y < 0 || p.x as usize >= W || p.y as usize >= H {
+            return None;
+        }
+        Some(self.cells[p.y as usize][p.x as usize])
+    }
+
+    /// Returns the position of the first `b`, scanning row by row
+    pub const fn find(&self, b: u8) -> Option<Point> {
+        let mut y = 0;
+        while y < H {
+            let mut x = 0;
+            while x < W {
+                if self.cells[y][x] == b {
+                    return Some(Point::new(x as isize, y as isize));
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+        None
+    }
+
+    /// Counts the cells equal to `b`
+    pub const fn count(&self, b: u8) -> usize {
+        let mut count = 0;
+        let mut y = 0;
+        while y < H {
+            let mut x = 0;
+            while x < W {
+                if self.cells[y][x] == b {
+                    count += 1;
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        ByteGrid, const_ints, count_ints, find_marker, grid_dims, line_count, lines, nth_line,
+        parse_int, parse_uint, split, split_trimmed,
+    };
+    use crate::util::Point;
+
+    const MAZE: &str = "#####\n#S..#\n#.#E#\n#####\n";
+
+    #[test]
+    fn test_lines() {
+        const LINES: [&str; line_count(MAZE)] = lines(MAZE);
+        assert_eq!(LINES, ["#####", "#S..#", "#.#E#", "#####"]);
+        assert_eq!(line_count("a\nb"), 2);
+        assert_eq!(line_count(""), 0);
+        assert_eq!(lines::<2>("a\nb"), ["a", "b"]);
+        assert_eq!(nth_line(MAZE, 2), "#.#E#");
+        assert_eq!(nth_line("a\nb", 1), "b");
+
+        // the day 19 input saved with CRLF line endings
+        const CRLF: &str = "r, wr, b\r\n\r\nbrwrr\r\nbggr\r\n";
+        assert_eq!(nth_line(CRLF, 0), "r, wr, b");
+        assert_eq!(lines::<4>(CRLF), ["r, wr, b", "", "brwrr", "bggr"]);
+    }
+
+    #[test]
+    fn test_split() {
+        const PATTERNS: [&str; 3] = split_trimmed("r, wr, b", b',');
+        assert_eq!(PATTERNS, ["r", "wr", "b"]);
+        assert_eq!(split::<3>("a||b", b'|'), ["a", "", "b"]);
+        assert_eq!(split::<1>("abc", b'|'), ["abc"]);
+    }
+
+    #[test]
+    fn test_ints() {
+        const N: usize = count_ints("p=0,4 v=3,-3");
+        const ROBOT: [isize; N] = const_ints("p=0,4 v=3,-3");
+        assert_eq!(ROBOT, [0, 4, 3, -3]);
+
+        assert_eq!(parse_int("-42"), -42);
+        assert_eq!(parse_int("+7"), 7);
+        assert_eq!(parse_uint("2024"), 2024);
+        assert_eq!(const_ints::<2>("5-3"), [5, -3]);
+    }
+
+    #[test]
+    fn test_grid() {
+        const DIMS: (usize, usize) = grid_dims(MAZE);
+        const GRID: ByteGrid<{ DIMS.0 }, { DIMS.1 }> = ByteGrid::parse(MAZE);
+        const START: Point = find_marker(MAZE, b'S').unwrap();
+
+        assert_eq!(DIMS, (5, 4));
+        assert_eq!(START, Point::new(1, 1));
+        assert_eq!(GRID.find(b'E'), Some(Point::new(3, 2)));
+        assert_eq!(GRID.get(START), Some(b'S'));
+        assert_eq!(GRID.get(Point::new(5, 0)), None);
+        assert_eq!(GRID.count(b'#'), 15);
+        assert_eq!(find_marker(MAZE, b'x'), None);
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::{HashMap, hash_map};
+use std::hash::Hash;
+
+/// A multiset that counts how often each key was added
+#[derive(Debug, Clone)]
+pub struct Counter<K> {
+    counts: HashMap<K, usize>,
+}
+
+impl<K> Default for Counter<K> {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<K> Counter<K>
+where
+    K: Eq + Hash,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, key: K) {
+        self.add_n(key, 1);
+    }
+
+    pub fn add_n(&mut self, key: K, n: usize) {
+        if n > 0 {
+            *self.counts.entry(key).or_default() += n;
+        }
+    }
+
+    /// Removes up to `n` of `key`, forgetting the key once its count reaches zero
+    pub fn subtract(&mut self, key: &K, n: usize) {
+        if let Some(count) = self.counts.get_mut(key) {
+            *count = count.saturating_sub(n);
+            if *count == 0 {
+                self.counts.remove(key);
+            }
+        }
+    }
+
+    /// How often `key` was added, which is 0 for unseen keys
+    pub fn get(&self, key: &K) -> usize {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// Number of distinct keys
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Sum of all counts
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Adds every count of `other` to this counter
+    pub fn merge(&mut self, other: Counter<K>) {
+        for (key, n) in other.counts {
+            self.add_n(key, n);
+        }
+    }
+
+    /// Iterates over the keys and their counts in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&K, usize)> {
+        self.counts.iter().map(|(k, &n)| (k, n))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.counts.keys()
+    }
+}
+
+impl<K> Counter<K>
+where
+    K: Eq + Hash + Ord,
+{
+    /// The keys and counts sorted by key
+    pub fn sorted_by_key(&self) -> Vec<(&K, usize)> {
+        let mut items: Vec<_> = self.iter().collect();
+        items.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        items
+    }
+
+    /// The keys and counts from most to least common, ties broken by key
+    pub fn sorted_by_count(&self) -> Vec<(&K, usize)> {
+        let mut items: Vec<_> = self.iter().collect();
+        items.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        items
+    }
+
+    /// The `n` most common keys with their counts, ties broken by key
+    pub fn most_common(&self, n: usize) -> Vec<(&K, usize)> {
+        let mut items = self.sorted_by_count();
+        items.truncate(n);
+        items
+    }
+}
+
+impl<K> Extend<K> for Counter<K>
+where
+    K: Eq + Hash,
+{
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.add(key);
+        }
+    }
+}
+
+impl<K> FromIterator<K> for Counter<K>
+where
+    K: Eq + Hash,
+{
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<K> IntoIterator for Counter<K> {
+    type Item = (K, usize);
+    type IntoIter = hash_map::IntoIter<K, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+/// Collects the values of `(key, value)` pairs under their keys, keeping each group in
+/// iteration order
+pub fn group_by_key<K, V>(pairs: impl IntoIterator<Item = (K, V)>) -> HashMap<K, Vec<V>>
+where
+    K: Eq + Hash,
+{
+    let mut groups: HashMap<K, Vec<V>> = HashMap::new();
+    for (key, value) in pairs {
+        groups.entry(key).or_default().push(value);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Counter, group_by_key};
+
+    #[test]
+    fn test_counter() {
+        let mut counter: Counter<char> = "mississippi".chars().collect();
+        assert_eq!(counter.get(&'s'), 4);
+        assert_eq!(counter.get(&'x'), 0);
+        assert_eq!((counter.len(), counter.total()), (4, 11));
+        assert_eq!(counter.most_common(2), [(&'i', 4), (&'s', 4)]);
+        assert_eq!(
+            counter.sorted_by_key(),
+            [(&'i', 4), (&'m', 1), (&'p', 2), (&'s', 4)]
+        );
+
+        counter.subtract(&'m', 3);
+        counter.subtract(&'p', 1);
+        assert_eq!(counter.get(&'m'), 0);
+        assert_eq!(counter.len(), 3);
+
+        counter.merge("spam".chars().collect());
+        assert_eq!(
+            counter.sorted_by_count(),
+            [(&'s', 5), (&'i', 4), (&'p', 2), (&'a', 1), (&'m', 1)]
+        );
+    }
+
+    #[test]
+    fn test_group_by_key() {
+        let fruit = ["apple", "avocado", "banana", "blueberry", "cherry"];
+        let groups = group_by_key(fruit.map(|s| (s.as_bytes()[0], s)));
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&b'a'], ["apple", "avocado"]);
+        assert_eq!(groups[&b'b'], ["banana", "blueberry"]);
+        assert_eq!(groups[&b'c'], ["cherry"]);
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Floyd's tortoise and hare.
+///
+/// Returns `(mu, lambda)`: the first state of the cycle is reached after `mu` steps and the
+/// cycle repeats every `lambda` steps. Only keeps two states around, but never returns if the
+/// states never repeat.
+pub fn floyd<S, F>(start: S, step: F) -> (usize, usize)
+where
+    S: Clone + Eq,
+    F: Fn(&S) -> S,
+{
+    let mut slow = step(&start);
+    let mut fast = step(&slow);
+    while slow != fast {
+        slow = step(&slow);
+        fast = step(&step(&fast));
+    }
+
+    let mut mu = 0;
+    slow = start;
+    while slow != fast {
+        slow = step(&slow);
+        fast = step(&fast);
+        mu += 1;
+    }
+
+    let mut lambda = 1;
+    fast = step(&slow);
+    while slow != fast {
+        fast = step(&fast);
+        lambda += 1;
+    }
+
+    (mu, lambda)
+}
+
+/// Brent's algorithm, which returns the same `(mu, lambda)` as [floyd] with fewer calls to `step`
+pub fn brent<S, F>(start: S, step: F) -> (usize, usize)
+where
+    S: Clone + Eq,
+    F: Fn(&S) -> S,
+{
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut slow = start.clone();
+    let mut fast = step(&start);
+    while slow != fast {
+        if power == lambda {
+            slow = fast.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        fast = step(&fast);
+        lambda += 1;
+    }
+
+    let mut slow = start.clone();
+    let mut fast = start;
+    for _ in 0..lambda {
+        fast = step(&fast);
+    }
+
+    let mut mu = 0;
+    while slow != fast {
+        slow = step(&slow);
+        fast = step(&fast);
+        mu += 1;
+    }
+
+    (mu, lambda)
+}
+
+/// Finds `(mu, lambda)` like [floyd] by remembering the step at which every state was first seen.
+/// Calls `step` exactly `mu + lambda` times, at the cost of storing every state.
+pub fn find_cycle<S, F>(start: S, step: F) -> (usize, usize)
+where
+    S: Clone + Hash + Eq,
+    F: Fn(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut state = start;
+    for i in 0.. {
+        if let Some(&mu) = seen.get(&state) {
+            return (mu, i - mu);
+        }
+        let next = step(&state);
+        seen.insert(state, i);
+        state = next;
+    }
+    unreachable!()
+}
+
+/// Returns the state after applying `step` `n` times.
+///
+/// The simulation runs until it either reaches step `n` or repeats a state. Once a cycle is
+/// found the remaining steps are skipped, so `n` can be far larger than the number of states.
+pub fn fast_forward<S, F>(start: S, step: F, n: usize) -> S
+where
+    S: Clone + Hash + Eq,
+    F: Fn(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = start;
+
+    for i in 0..n {
+        if let Some(&mu) = seen.get(&state) {
+            let lambda = i - mu;
+            return history.swap_remove(mu + (n - mu) % lambda);
+        }
+        seen.insert(state.clone(), i);
+        history.push(state.clone());
+        state = step(&state);
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod test {
+    use super::{brent, fast_forward, find_cycle, floyd};
+    use crate::util::Point;
+
+    /// `0, 1, ... tail - 1` followed by a cycle of length `len`
+    fn rho(tail: usize, len: usize) -> impl Fn(&usize) -> usize {
+        move |&s| if s + 1 == tail + len { tail } else { s + 1 }
+    }
+
+    #[test]
+    fn test_detectors_agree() {
+        for tail in 0..10 {
+            for len in 1..10 {
+                let expected = (tail, len);
+                assert_eq!(floyd(0, rho(tail, len)), expected);
+                assert_eq!(brent(0, rho(tail, len)), expected);
+                assert_eq!(find_cycle(0, rho(tail, len)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lcg() {
+        let step = |&s: &u64| (s * s + 1) % 255;
+        let expected = find_cycle(3, step);
+        assert_eq!(floyd(3, step), expected);
+        assert_eq!(brent(3, step), expected);
+    }
+
+    #[test]
+    fn test_fast_forward() {
+        for n in 0..40 {
+            let expected = (0..n).fold(0, |s, _| rho(4, 7)(&s));
+            assert_eq!(fast_forward(0, rho(4, 7), n), expected, "{n}");
+        }
+
+        // a robot on the 2024 day 14 floor is back where it started every 101 * 103 seconds
+        let bounds = Point::new(101, 103);
+        let vel = Point::new(-37, 52);
+        let robot = |p: &Point| (*p + vel).rem_euclid(bounds);
+        let start = Point::new(5, 9);
+
+        assert_eq!(find_cycle(start, robot), (0, 101 * 103));
+        assert_eq!(
+            fast_forward(start, robot, 1_000_000_007),
+            (start + vel * 1_000_000_007).rem_euclid(bounds)
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use num_traits::PrimInt;
+
+/// Integers whose decimal digits can be counted in their own width
+pub trait DigitCount: PrimInt {
+    /// Number of decimal digits, ignoring the sign. `0` has one digit.
+    fn digit_count(self) -> usize;
+}
+
+macro_rules! impl_digit_count {
+    ($($t: ty),+) => {
+        $(impl DigitCount for $t {
+            fn digit_count(self) -> usize {
+                self.checked_ilog10().map_or(1, |log| log as usize + 1)
+            }
+        })+
+    };
+    ($($t: ty => $u: ty),+) => {
+        $(impl DigitCount for $t {
+            fn digit_count(self) -> usize {
+                self.unsigned_abs().digit_count()
+            }
+        })+
+    };
+}
+
+impl_digit_count!(u8, u16, u32, u64, u128, usize);
+impl_digit_count!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+/// Number of decimal digits in `n`, ignoring the sign. `0` has one digit.
+pub fn num_digits<T: DigitCount>(n: T) -> usize {
+    n.digit_count()
+}
+
+/// Returns `10^exp` as a `T`. Panics if it does not fit.
+pub fn pow10<T: PrimInt>(exp: usize) -> T {
+    T::from(10).unwrap().pow(exp as u32)
+}
+
+/// Splits off the last `k` decimal digits: `split_at_digit(123456, 2) == (1234, 56)`
+pub fn split_at_digit<T: PrimInt>(n: T, k: usize) -> (T, T) {
+    let divisor = pow10::<T>(k);
+    (n / divisor, n % divisor)
+}
+
+/// Splits a number with an even digit count into its two halves, e.g. `1234` into `(12, 34)`.
+/// Returns `None` if the digit count is odd.
+pub fn split_in_half<T: DigitCount>(n: T) -> Option<(T, T)> {
+    let d = num_digits(n);
+    d.is_multiple_of(2).then(|| split_at_digit(n, d / 2))
+}
+
+/// Appends the decimal digits of `b` to `a`: `concat(12, 345) == 12345`
+pub fn concat<T: DigitCount>(a: T, b: T) -> T {
+    a * pow10::<T>(num_digits(b)) + b
+}
+
+/// Iterates over the digits of `n` in base `base`, least significant first.
+/// `0` yields a single `0` digit. Panics if `n` is negative.
+pub fn digits<T: PrimInt>(n: T, base: T) -> Digits<T> {
+    assert!(base > T::one(), "base must be at least 2");
+    assert!(
+        n >= T::zero(),
+        "cannot take the digits of a negative number"
+    );
+    Digits { n: Some(n), base }
+}
+
+/// Builds a number from `digits` in base `base`, most significant first
+pub fn from_digits<T: PrimInt, I: IntoIterator<Item = T>>(digits: I, base: T) -> T {
+    digits.into_iter().fold(T::zero(), |acc, d| acc * base + d)
+}
+
+/// Reverses the digits of `n` in base `base`: `reverse_digits(1230, 10) == 321`.
+/// Panics if `n` is negative.
+pub fn reverse_digits<T: PrimInt>(n: T, base: T) -> T {
+    from_digits(digits(n, base), base)
+}
+
+/// Iterator returned by [digits]
+#[derive(Debug, Clone)]
+pub struct Digits<T> {
+    n: Option<T>,
+    base: T,
+}
+
+impl<T: PrimInt> Iterator for Digits<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.n?;
+        let rest = n / self.base;
+        self.n = (!rest.is_zero()).then_some(rest);
+        Some(n % self.base)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        concat, digits, from_digits, num_digits, reverse_digits, split_at_digit, split_in_half,
+    };
+
+    #[test]
+    fn test_num_digits() {
+        assert_eq!(num_digits(0), 1);
+        assert_eq!(num_digits(9u8), 1);
+        assert_eq!(num_digits(255u8), 3);
+        assert_eq!(num_digits(-1000i32), 4);
+        assert_eq!(num_digits(i64::MIN), 19);
+        assert_eq!(num_digits(u64::MAX), 20);
+
+        for exp in 1..20 {
+            let p = 10usize.pow(exp);
+            assert_eq!(num_digits(p - 1), exp as usize);
+            assert_eq!(num_digits(p), exp as usize + 1);
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(split_at_digit(123456, 2), (1234, 56));
+        assert_eq!(split_at_digit(123456, 0), (123456, 0));
+        assert_eq!(split_in_half(1000usize), Some((10, 0)));
+        assert_eq!(split_in_half(253000usize), Some((253, 0)));
+        assert_eq!(split_in_half(125usize), None);
+    }
+
+    #[test]
+    fn test_concat() {
+        assert_eq!(concat(12, 345), 12345);
+        assert_eq!(concat(15u64, 6), 156);
+        assert_eq!(concat(1, 0), 10);
+        assert_eq!(concat(0, 7), 7);
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digits(1234, 10).collect::<Vec<_>>(), [4, 3, 2, 1]);
+        assert_eq!(digits(0, 10).collect::<Vec<_>>(), [0]);
+        assert_eq!(digits(0b1011u8, 2).collect::<Vec<_>>(), [1, 1, 0, 1]);
+        assert_eq!(digits(255, 16).collect::<Vec<_>>(), [15, 15]);
+
+        assert_eq!(from_digits([1, 2, 3], 10), 123);
+        assert_eq!(from_digits([1, 0, 1, 1], 2), 11);
+        assert_eq!(reverse_digits(1230, 10), 321);
+        assert_eq!(reverse_digits(0b1101u32, 2), 0b1011);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reverse_negative() {
+        reverse_digits(-120, 10);
+    }
+}
@@ -1,8 +1,8 @@
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap};
+use std::collections::BinaryHeap;
 use std::fmt::Debug;
 
-use super::{Grid, Point};
+use super::{Grid, Point, PointMap};
 
 pub trait Walkable<T: Copy + Debug> {
     /// The type used to measure distance/weight (e.g., i32, f32, u64).
@@ -72,7 +72,7 @@ where
     }
 }
 
-type PrevMap = HashMap<Point, Point>;
+type PrevMap = PointMap<Point>;
 fn reconstruct(point: Point, previous: &mut PrevMap) -> Vec<Point> {
     let mut acc = vec![];
     let mut current_point = point;
@@ -80,8 +80,8 @@ fn reconstruct(point: Point, previous: &mut PrevMap) -> Vec<Point> {
     loop {
         acc.push(current_point);
 
-        match previous.get(&current_point) {
-            Some(previous) => current_point = *previous,
+        match previous.get(current_point) {
+            Some(previous) => current_point = previous,
             None => break,
         }
     }
@@ -102,8 +102,8 @@ where
         return None;
     }
 
-    let mut distance: HashMap<Point, W::Cost> = HashMap::new();
-    let mut previous: PrevMap = HashMap::new();
+    let mut distance: PointMap<W::Cost> = PointMap::for_grid(grid);
+    let mut previous: PrevMap = PointMap::for_grid(grid);
     let mut priority_q: BinaryHeap<Node<W, T>> = BinaryHeap::new();
 
     distance.insert(start, W::zero());
@@ -117,8 +117,8 @@ where
             return Some(reconstruct(pos, &mut previous));
         }
 
-        let is_stale = match distance.get(&pos) {
-            Some(best) => W::cmp(&cost, best) == Ordering::Greater,
+        let is_stale = match distance.get(pos) {
+            Some(best) => W::cmp(&cost, &best) == Ordering::Greater,
             None => true,
         };
 
@@ -135,8 +135,8 @@ where
             };
 
             let new_cost = W::add(cost, W::cost_of(cell));
-            let is_improvement = match distance.get(&nbor) {
-                Some(best_cost) => W::cmp(&new_cost, best_cost) == Ordering::Less,
+            let is_improvement = match distance.get(nbor) {
+                Some(best_cost) => W::cmp(&new_cost, &best_cost) == Ordering::Less,
                 None => true,
             };
 
@@ -1,13 +1,127 @@
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash)]
+use std::str::FromStr;
+
+use super::{ParseError, Point};
+
+/// A compass direction on a grid where `y` grows downwards.
+///
+/// The cardinal directions come first so `dir.index()` can index `[T; 4]` tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Direction {
-    North,
-    NorthWest,
-    West,
-    SouthWest,
-    South,
-    SouthEast,
-    East,
-    NorthEast,
+    North = 0,
+    East = 1,
+    South = 2,
+    West = 3,
+    NorthEast = 4,
+    SouthEast = 5,
+    SouthWest = 6,
+    NorthWest = 7,
+}
+
+impl Direction {
+    /// The four cardinal directions, starting at `North` and rotating clockwise
+    pub const CARDINAL: [Direction; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// All eight directions, starting at `North` and rotating clockwise
+    pub const ALL: [Direction; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Returns `0..4` for the cardinal directions and `4..8` for the intercardinal ones
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the unit step taken when moving one cell in this direction
+    pub const fn to_point(self) -> Point {
+        match self {
+            Self::North => Point::new(0, -1),
+            Self::East => Point::new(1, 0),
+            Self::South => Point::new(0, 1),
+            Self::West => Point::new(-1, 0),
+            Self::NorthEast => Point::new(1, -1),
+            Self::SouthEast => Point::new(1, 1),
+            Self::SouthWest => Point::new(-1, 1),
+            Self::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// Rotates 90 degrees clockwise
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+            Self::NorthEast => Self::SouthEast,
+            Self::SouthEast => Self::SouthWest,
+            Self::SouthWest => Self::NorthWest,
+            Self::NorthWest => Self::NorthEast,
+        }
+    }
+
+    /// Rotates 90 degrees counter-clockwise
+    pub const fn turn_left(self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    /// Rotates 180 degrees
+    pub const fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// Returns true for `North`, `East`, `South` and `West`
+    pub const fn is_cardinal(self) -> bool {
+        self.index() < 4
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    /// Parses arrows (`^v<>`), `UDLR` or `NESW`
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        let dir = match ch {
+            '^' | 'U' | 'N' => Self::North,
+            '>' | 'R' | 'E' => Self::East,
+            'v' | 'D' | 'S' => Self::South,
+            '<' | 'L' | 'W' => Self::West,
+            _ => return Err(ParseError::new(format!("invalid direction {ch:?}"))),
+        };
+
+        Ok(dir)
+    }
+}
+
+impl TryFrom<u8> for Direction {
+    type Error = ParseError;
+
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        Self::try_from(b as char)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    /// Parses a single character accepted by `TryFrom<char>` or a name as printed by `Display`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(ch), None) = (chars.next(), chars.next()) {
+            return Self::try_from(ch);
+        }
+
+        Self::ALL
+            .into_iter()
+            .find(|d| d.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ParseError::new(format!("invalid direction {s:?}")))
+    }
 }
 
 impl std::fmt::Display for Direction {
@@ -26,3 +140,57 @@ impl std::fmt::Display for Direction {
         write!(f, "{s}")
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Direction;
+    use crate::util::Point;
+
+    #[test]
+    fn test_turns() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.opposite().opposite(), d);
+            assert_eq!(d.to_point() + d.opposite().to_point(), Point::origin());
+            assert_eq!(d.turn_right().is_cardinal(), d.is_cardinal());
+        }
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthEast.turn_right(), Direction::SouthEast);
+        assert_eq!(Direction::SouthWest.opposite(), Direction::NorthEast);
+    }
+
+    #[test]
+    fn test_parse() {
+        let arrows: Vec<_> = "^>v<"
+            .chars()
+            .map(|c| Direction::try_from(c).unwrap())
+            .collect();
+        let letters: Vec<_> = "URDL"
+            .chars()
+            .map(|c| Direction::try_from(c).unwrap())
+            .collect();
+        let compass: Vec<_> = "NESW"
+            .chars()
+            .map(|c| Direction::try_from(c).unwrap())
+            .collect();
+
+        assert_eq!(arrows, Direction::CARDINAL);
+        assert_eq!(letters, Direction::CARDINAL);
+        assert_eq!(compass, Direction::CARDINAL);
+        assert_eq!(
+            "southwest".parse::<Direction>().unwrap(),
+            Direction::SouthWest
+        );
+        assert!(Direction::try_from('x').is_err());
+        assert!("up".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn test_points_match_nbor8() {
+        let p = Point::new(3, 3);
+        let steps = Direction::ALL.map(|d| p.step(d));
+        assert_eq!(steps, p.nbor8());
+    }
+}
@@ -0,0 +1,231 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+/// Returned when a dependency graph cannot be ordered.
+/// `cycle` lists the nodes of one cycle in edge order; the last node depends on the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<N> {
+    pub cycle: Vec<N>,
+}
+
+impl<N: Debug> Display for CycleError<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "dependency cycle: {:?}", self.cycle)
+    }
+}
+
+impl<N: Debug> std::error::Error for CycleError<N> {}
+
+/// Orders `nodes` so every edge `(a, b)` puts `a` before `b`, using Kahn's algorithm.
+///
+/// Among the nodes that are ready at the same time the smallest comes first, so the result is
+/// the lexicographically smallest valid order. Nodes that only appear in `edges` are included.
+pub fn topo_sort<N, I, E>(nodes: I, edges: E) -> Result<Vec<N>, CycleError<N>>
+where
+    N: Copy + Hash + Ord,
+    I: IntoIterator<Item = N>,
+    E: IntoIterator<Item = (N, N)>,
+{
+    let mut successors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut in_degree: HashMap<N, usize> = nodes.into_iter().map(|n| (n, 0)).collect();
+
+    for (a, b) in edges.into_iter().collect::<HashSet<_>>() {
+        successors.entry(a).or_default().push(b);
+        in_degree.entry(a).or_default();
+        *in_degree.entry(b).or_default() += 1;
+    }
+
+    let mut ready: BinaryHeap<_> = in_degree
+        .iter()
+        .filter(|(_, d)| **d == 0)
+        .map(|(&n, _)| Reverse(n))
+        .collect();
+    let mut order = Vec::with_capacity(in_degree.len());
+
+    while let Some(Reverse(n)) = ready.pop() {
+        order.push(n);
+        for s in successors.get(&n).into_iter().flatten() {
+            let d = in_degree.get_mut(s).unwrap();
+            *d -= 1;
+            if *d == 0 {
+                ready.push(Reverse(*s));
+            }
+        }
+    }
+
+    if order.len() == in_degree.len() {
+        Ok(order)
+    } else {
+        Err(CycleError {
+            cycle: find_cycle(&successors, &in_degree),
+        })
+    }
+}
+
+/// Every node left with a non-zero in-degree after Kahn's algorithm has a predecessor that is
+/// also left over, so walking predecessors from any of them must end up in a cycle.
+fn find_cycle<N: Copy + Hash + Ord>(
+    successors: &HashMap<N, Vec<N>>,
+    in_degree: &HashMap<N, usize>,
+) -> Vec<N> {
+    let stuck = |n: &N| in_degree[n] > 0;
+    let mut predecessor = HashMap::new();
+    for (a, bs) in successors.iter().filter(|(a, _)| stuck(a)) {
+        for b in bs.iter().filter(|b| stuck(b)) {
+            predecessor.entry(*b).or_insert(*a);
+        }
+    }
+
+    let mut n = *in_degree.keys().filter(|n| stuck(n)).min().unwrap();
+    let mut seen = HashSet::new();
+    while seen.insert(n) {
+        n = predecessor[&n];
+    }
+
+    let mut cycle = vec![n];
+    let mut p = predecessor[&n];
+    while p != n {
+        cycle.push(p);
+        p = predecessor[&p];
+    }
+    cycle.reverse();
+
+    let first = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+    cycle.rotate_left(first);
+    cycle
+}
+
+/// A set of `a before b` rules, such as the page ordering rules of 2024 day 5
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartialOrder<N: Hash + Eq> {
+    rules: HashSet<(N, N)>,
+}
+
+impl<N: Copy + Hash + Ord> PartialOrder<N> {
+    pub fn new() -> Self {
+        Self {
+            rules: HashSet::new(),
+        }
+    }
+
+    /// Adds the rule that `a` comes before `b`
+    pub fn insert(&mut self, a: N, b: N) {
+        self.rules.insert((a, b));
+    }
+
+    /// Returns true if there is a rule putting `a` before `b`
+    pub fn before(&self, a: &N, b: &N) -> bool {
+        self.rules.contains(&(*a, *b))
+    }
+
+    /// A comparator for `sort_by`. Values without a rule between them compare as equal.
+    ///
+    /// This is only a valid total order if every pair being sorted has a rule, otherwise use
+    /// [PartialOrder::topo_sort].
+    pub fn compare(&self, a: &N, b: &N) -> Ordering {
+        if self.before(a, b) {
+            Ordering::Less
+        } else if self.before(b, a) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Returns true if no rule puts a later value before an earlier one
+    pub fn is_sorted(&self, values: &[N]) -> bool {
+        values
+            .iter()
+            .enumerate()
+            .all(|(i, a)| values[i + 1..].iter().all(|b| !self.before(b, a)))
+    }
+
+    /// Orders `values` using only the rules between them
+    pub fn topo_sort(&self, values: &[N]) -> Result<Vec<N>, CycleError<N>> {
+        let present: HashSet<_> = values.iter().collect();
+        let edges = self
+            .rules
+            .iter()
+            .filter(|(a, b)| present.contains(a) && present.contains(b))
+            .copied();
+        topo_sort(values.iter().copied(), edges)
+    }
+}
+
+impl<N: Copy + Hash + Ord> FromIterator<(N, N)> for PartialOrder<N> {
+    fn from_iter<T: IntoIterator<Item = (N, N)>>(iter: T) -> Self {
+        Self {
+            rules: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<N: Copy + Hash + Ord> Extend<(N, N)> for PartialOrder<N> {
+    fn extend<T: IntoIterator<Item = (N, N)>>(&mut self, iter: T) {
+        self.rules.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CycleError, PartialOrder, topo_sort};
+
+    #[test]
+    fn test_topo_sort() {
+        // the 2018 day 7 example
+        let edges = [
+            ('C', 'A'),
+            ('C', 'F'),
+            ('A', 'B'),
+            ('A', 'D'),
+            ('B', 'E'),
+            ('D', 'E'),
+            ('F', 'E'),
+        ];
+        let order: String = topo_sort([], edges).unwrap().into_iter().collect();
+        assert_eq!(order, "CABDFE");
+
+        assert_eq!(topo_sort([3, 1, 2], []), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let edges = [(1, 2), (2, 3), (3, 4), (4, 2), (0, 1)];
+        let err = topo_sort([5], edges).unwrap_err();
+        assert_eq!(
+            err,
+            CycleError {
+                cycle: vec![2, 3, 4]
+            }
+        );
+        assert_eq!(err.to_string(), "dependency cycle: [2, 3, 4]");
+
+        let err = topo_sort([], [(7, 7)]).unwrap_err();
+        assert_eq!(err.cycle, [7]);
+    }
+
+    #[test]
+    fn test_partial_order() {
+        let rules: PartialOrder<_> = [(97, 75), (97, 47), (75, 47), (47, 29)]
+            .into_iter()
+            .collect();
+
+        assert!(rules.is_sorted(&[97, 75, 47, 29]));
+        assert!(!rules.is_sorted(&[75, 97, 47]));
+        assert!(rules.is_sorted(&[29, 1]));
+
+        let mut update = [47, 75, 97];
+        update.sort_by(|a, b| rules.compare(a, b));
+        assert_eq!(update, [97, 75, 47]);
+
+        // 29 and 97 have no direct rule, so only the topological sort orders them correctly
+        assert_eq!(rules.topo_sort(&[29, 47, 97, 75]), Ok(vec![97, 75, 47, 29]));
+        assert_eq!(rules.topo_sort(&[29, 97]), Ok(vec![29, 97]));
+
+        let mut cyclic = rules.clone();
+        cyclic.insert(29, 97);
+        assert!(cyclic.topo_sort(&[97, 75, 47, 29]).is_err());
+    }
+}
@@ -4,7 +4,7 @@ use std::str::FromStr;
 
 use anyhow::Result;
 
-use super::Point;
+use super::{Point, Render};
 
 pub type Entry<T> = (isize, isize, T);
 
@@ -237,9 +237,8 @@ impl<T: Debug + Copy> Grid<T> {
     /// Returns `Some(&mut T)` if the coordinates are within the grid bounds,
     /// otherwise returns `None`.
     pub fn get_mut<P: GridPoint>(&mut self, p: P) -> Option<&mut T> {
-        let (x, y) = p.to_coordinate_pair();
-        if y < self.height_i && x < self.width_i {
-            Some(&mut self[(x, y)])
+        if self.inside(p) {
+            Some(&mut self[p])
         } else {
             None
         }
@@ -336,6 +335,7 @@ impl<T: Debug + Copy> Grid<T> {
         }
 
         self.width -= 2;
+        self.width_i -= 2;
 
         self
     }
@@ -349,6 +349,7 @@ impl<T: Debug + Copy> Grid<T> {
         self.inner.remove(0);
         self.inner.pop();
         self.height -= 2;
+        self.height_i -= 2;
 
         self
     }
@@ -410,7 +411,7 @@ impl<T: Debug + Copy> Grid<T> {
             width: self.width,
             width_i: self.width_i,
             height: self.height,
-            height_i: self.width_i,
+            height_i: self.height_i,
         }
     }
 
@@ -431,7 +432,7 @@ impl<T: Debug + Copy> Grid<T> {
             width: self.width,
             width_i: self.width_i,
             height: self.height,
-            height_i: self.width_i,
+            height_i: self.height_i,
         }
     }
 }
@@ -444,7 +445,7 @@ impl Grid<char> {
             width: self.width,
             width_i: self.width_i,
             height: self.height,
-            height_i: self.width_i,
+            height_i: self.height_i,
             inner: self
                 .inner
                 .into_iter()
@@ -458,30 +459,10 @@ impl Grid<char> {
     }
 }
 
-impl<T: Debug + Copy> Display for Grid<T> {
+/// Draws the grid with [Grid::render], one glyph per cell
+impl<T: Debug + Copy + Render> Display for Grid<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if self.inner.is_empty() {
-            return Ok(());
-        }
-
-        let mut col_widths = vec![0; self.width];
-        for row in &self.inner {
-            for (i, value) in row.iter().enumerate() {
-                let s = format!("{value:?}");
-                if s.len() > col_widths[i] {
-                    col_widths[i] = s.len();
-                }
-            }
-        }
-
-        for row in &self.inner {
-            for (i, value) in row.iter().enumerate() {
-                write!(f, " {:>width$?}", value, width = col_widths[i])?;
-            }
-            writeln!(f)?;
-        }
-
-        Ok(())
+        write!(f, "{}", self.render())
     }
 }
 
@@ -710,4 +691,15 @@ mod tests {
         valid_grid.strip_boundaries();
         assert_eq!(valid_grid, expected_valid, "A 3x5 grid should be stripped");
     }
+
+    #[test]
+    fn test_get_mut_outside() {
+        let mut grid = Grid::make(2, 3, 0);
+        assert!(grid.get_mut((-1, 0)).is_none());
+        assert!(grid.get_mut((0, -1)).is_none());
+        assert!(grid.get_mut((3, 0)).is_none());
+
+        *grid.get_mut((2, 1)).unwrap() = 5;
+        assert_eq!(grid[(2, 1)], 5);
+    }
 }
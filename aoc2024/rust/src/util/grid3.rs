@@ -0,0 +1,208 @@
+use std::fmt::Debug;
+use std::ops::{Index, IndexMut};
+
+use super::Point3;
+
+/// A trait for types that can be converted into 3D grid coordinates, the 3D counterpart of
+/// `GridPoint`
+pub trait GridPoint3: Debug + Copy {
+    /// Returns the (x, y, z) coordinates represented by this index.
+    fn to_coordinate_triple(&self) -> (isize, isize, isize);
+
+    /// Returns the `Point3` represented by this index.
+    fn to_point(&self) -> Point3 {
+        let (x, y, z) = self.to_coordinate_triple();
+        Point3::xyz(x, y, z)
+    }
+}
+
+impl GridPoint3 for Point3 {
+    fn to_coordinate_triple(&self) -> (isize, isize, isize) {
+        (self.x(), self.y(), self.z())
+    }
+
+    fn to_point(&self) -> Point3 {
+        *self
+    }
+}
+
+impl GridPoint3 for (isize, isize, isize) {
+    fn to_coordinate_triple(&self) -> (isize, isize, isize) {
+        *self
+    }
+}
+
+/// A dense, fixed size 3D grid stored as `depth` layers of `height` rows of `width` cells
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid3<T: Debug + Copy> {
+    inner: Vec<T>,
+    pub width: usize,
+    pub height: usize,
+    pub depth: usize,
+}
+
+#[allow(unused)]
+impl<T: Debug + Copy> Grid3<T> {
+    /// Initializes a `depth` by `height` by `width` grid where all values are `value`
+    pub fn make(depth: usize, height: usize, width: usize, value: T) -> Self {
+        Self {
+            inner: vec![value; depth * height * width],
+            width,
+            height,
+            depth,
+        }
+    }
+
+    /// Returns true if the point is contained in the grid
+    pub fn inside<P: GridPoint3>(&self, p: P) -> bool {
+        let (x, y, z) = p.to_coordinate_triple();
+        (0..self.width as isize).contains(&x)
+            && (0..self.height as isize).contains(&y)
+            && (0..self.depth as isize).contains(&z)
+    }
+
+    /// The index of `p` in `inner`, which is only meaningful for points inside the grid
+    fn offset<P: GridPoint3>(&self, p: P) -> usize {
+        let (x, y, z) = p.to_coordinate_triple();
+        (z as usize * self.height + y as usize) * self.width + x as usize
+    }
+
+    /// Safely gets an immutable reference to a value in the grid.
+    /// Returns `None` if the point is outside the grid.
+    pub fn get<P: GridPoint3>(&self, p: P) -> Option<&T> {
+        self.inside(p).then(|| &self.inner[self.offset(p)])
+    }
+
+    /// Safely gets a mutable reference to a value in the grid.
+    /// Returns `None` if the point is outside the grid.
+    pub fn get_mut<P: GridPoint3>(&mut self, p: P) -> Option<&mut T> {
+        if self.inside(p) {
+            let i = self.offset(p);
+            Some(&mut self.inner[i])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over every point and its value, layer by layer
+    pub fn entries(&self) -> impl Iterator<Item = (Point3, &T)> + '_ {
+        let (w, h) = (self.width, self.height);
+        self.inner.iter().enumerate().map(move |(i, v)| {
+            let p = Point3::xyz(
+                (i % w) as isize,
+                (i / w % h) as isize,
+                (i / (w * h)) as isize,
+            );
+            (p, v)
+        })
+    }
+
+    /// Returns the values of the six face-adjacent neighbors of `p`.
+    /// A neighbor is [None] if it is out of bounds.
+    pub fn nbor6_values<P: GridPoint3>(&self, p: P) -> impl Iterator<Item = Option<&T>> + '_ {
+        let p = p.to_point();
+        (0..3).flat_map(move |axis| {
+            [-1, 1].map(|delta| {
+                let mut n = p;
+                n[axis] += delta;
+                self.get(n)
+            })
+        })
+    }
+
+    /// Returns the values of all 26 surrounding neighbors of `p`.
+    /// A neighbor is [None] if it is out of bounds.
+    pub fn nbor26_values<P: GridPoint3>(&self, p: P) -> impl Iterator<Item = Option<&T>> + '_ {
+        let p = p.to_point();
+        (0..27)
+            .filter(|&i| i != 13)
+            .map(move |i| p + Point3::xyz(i % 3 - 1, i / 3 % 3 - 1, i / 9 - 1))
+            .map(|n| self.get(n))
+    }
+
+    /// Counts the values that satisfy `pred`
+    pub fn count<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.inner.iter().filter(|v| pred(v)).count()
+    }
+
+    /// Applies the function `f` to each value `v` in the grid, producing a new grid
+    pub fn map_values<F, U>(self, f: F) -> Grid3<U>
+    where
+        F: Fn(T) -> U,
+        U: Debug + Copy,
+    {
+        Grid3 {
+            inner: self.inner.into_iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            depth: self.depth,
+        }
+    }
+}
+
+impl<T: Debug + Copy, P: GridPoint3> Index<P> for Grid3<T> {
+    type Output = T;
+
+    fn index(&self, p: P) -> &Self::Output {
+        assert!(self.inside(p), "{p:?} is outside the grid");
+        &self.inner[self.offset(p)]
+    }
+}
+
+impl<T: Debug + Copy, P: GridPoint3> IndexMut<P> for Grid3<T> {
+    fn index_mut(&mut self, p: P) -> &mut Self::Output {
+        assert!(self.inside(p), "{p:?} is outside the grid");
+        let i = self.offset(p);
+        &mut self.inner[i]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Grid3;
+    use crate::util::Point3;
+
+    #[test]
+    fn test_index() {
+        let mut grid = Grid3::make(2, 3, 4, 0);
+        grid[(3, 2, 1)] = 7;
+        grid[Point3::xyz(0, 1, 0)] = 5;
+
+        assert_eq!(grid.get((3, 2, 1)), Some(&7));
+        assert_eq!(grid.get((4, 0, 0)), None);
+        assert_eq!(grid.get((0, 0, -1)), None);
+
+        let found: Vec<_> = grid.entries().filter(|(_, v)| **v != 0).collect();
+        assert_eq!(
+            found,
+            [(Point3::xyz(0, 1, 0), &5), (Point3::xyz(3, 2, 1), &7)]
+        );
+    }
+
+    #[test]
+    fn test_nbors() {
+        let grid = Grid3::make(3, 3, 3, true);
+
+        assert_eq!(grid.nbor26_values((1, 1, 1)).flatten().count(), 26);
+        assert_eq!(grid.nbor26_values((0, 0, 0)).flatten().count(), 7);
+        assert_eq!(grid.nbor6_values((1, 1, 1)).flatten().count(), 6);
+        assert_eq!(grid.nbor6_values((0, 1, 2)).flatten().count(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_outside() {
+        let grid = Grid3::make(2, 2, 2, 0);
+        _ = grid[(2, 0, 0)];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_mut_negative() {
+        let mut grid = Grid3::make(2, 2, 2, 0);
+        grid[(0, -1, 1)] = 1;
+    }
+}
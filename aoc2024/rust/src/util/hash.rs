@@ -0,0 +1,193 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::hash::{BuildHasherDefault, Hasher};
+
+use super::{Grid, Point};
+
+/// The multiplier used by rustc's FxHash
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// A fast, non-cryptographic hasher in the style of rustc's FxHash.
+///
+/// It mixes one word at a time with a rotate, xor and multiply, which is much cheaper than
+/// SipHash for the small integer and point keys of the puzzles. It offers no protection against
+/// crafted collisions, so only use it for trusted keys.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        for &b in chunks.remainder() {
+            self.add(b as u64);
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+/// A `HashMap` using [FxHasher], created with `FastMap::default()`
+pub type FastMap<K, V> = HashMap<K, V, FxBuildHasher>;
+/// A `HashSet` using [FxHasher], created with `FastSet::default()`
+pub type FastSet<K> = HashSet<K, FxBuildHasher>;
+
+/// A map from the points of a `height` by `width` area to values, stored densely in a [Grid] so
+/// lookups are a bounds check and an index instead of a hash.
+/// Points outside the area are never present, and inserting one panics.
+#[derive(Debug, Clone)]
+pub struct PointMap<V: Debug + Copy> {
+    cells: Grid<Option<V>>,
+    len: usize,
+}
+
+impl<V: Debug + Copy> PointMap<V> {
+    /// Creates an empty map, taking `height` and `width` in the same order as `Grid::make`
+    pub fn new(height: usize, width: usize) -> Self {
+        Self {
+            cells: Grid::make(height, width, None),
+            len: 0,
+        }
+    }
+
+    /// Creates an empty map covering the same area as `grid`
+    pub fn for_grid<T: Debug + Copy>(grid: &Grid<T>) -> Self {
+        Self::new(grid.height, grid.width)
+    }
+
+    /// Number of points with a value
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, p: Point) -> Option<V> {
+        self.cells.get(p).copied().flatten()
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.get(p).is_some()
+    }
+
+    /// Sets the value at `p`, returning the old one
+    pub fn insert(&mut self, p: Point, value: V) -> Option<V> {
+        assert!(self.cells.inside(p), "{p:?} is outside the map");
+        let old = self.cells[p].replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn remove(&mut self, p: Point) -> Option<V> {
+        if !self.cells.inside(p) {
+            return None;
+        }
+        let old = self.cells[p].take();
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+
+    /// Iterates over the points with a value, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, V)> + '_ {
+        self.cells
+            .entries()
+            .filter_map(|(x, y, &v)| v.map(|v| (Point::new(x, y), v)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::hash::{BuildHasher, Hash};
+
+    use super::{FastMap, FastSet, FxBuildHasher, PointMap};
+    use crate::util::Point;
+
+    fn fx_hash<T: Hash>(value: T) -> u64 {
+        FxBuildHasher::default().hash_one(value)
+    }
+
+    #[test]
+    fn test_fx_hash() {
+        assert_eq!(fx_hash(Point::new(3, 4)), fx_hash(Point::new(3, 4)));
+        assert_ne!(fx_hash(Point::new(3, 4)), fx_hash(Point::new(4, 3)));
+        assert_ne!(fx_hash("abcdefghi"), fx_hash("abcdefghj"));
+
+        let hashes: FastSet<u64> = (0..100)
+            .flat_map(|x| (0..100).map(move |y| fx_hash((x, y))))
+            .collect();
+        assert_eq!(hashes.len(), 10_000);
+
+        let mut map: FastMap<&str, usize> = FastMap::default();
+        map.insert("a", 1);
+        *map.entry("a").or_default() += 1;
+        assert_eq!(map["a"], 2);
+    }
+
+    #[test]
+    fn test_point_map() {
+        let mut map: PointMap<char> = PointMap::new(2, 3);
+        assert!(map.is_empty());
+        assert_eq!(map.insert(Point::new(2, 1), 'a'), None);
+        assert_eq!(map.insert(Point::new(2, 1), 'b'), Some('a'));
+        map.insert(Point::new(0, 0), 'c');
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(Point::new(2, 1)), Some('b'));
+        assert_eq!(map.get(Point::new(1, 1)), None);
+        assert_eq!(map.get(Point::new(-1, 0)), None);
+        assert!(!map.contains(Point::new(3, 0)));
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            [(Point::new(0, 0), 'c'), (Point::new(2, 1), 'b')]
+        );
+
+        assert_eq!(map.remove(Point::new(0, 0)), Some('c'));
+        assert_eq!(map.remove(Point::new(9, 9)), None);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_point_map_outside() {
+        PointMap::new(2, 2).insert(Point::new(2, 0), 1);
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt::Debug;
+use std::mem::MaybeUninit;
+
+/// An allocation-free binary max-heap holding at most `N` items.
+/// Wrap items in `std::cmp::Reverse` to pop the smallest first, the same way as `BinaryHeap`.
+#[derive(Clone, Copy)]
+pub struct Heap<T, const N: usize>
+where
+    T: Copy + Ord,
+{
+    length: usize,
+    items: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> Default for Heap<T, N>
+where
+    T: Copy + Ord,
+{
+    fn default() -> Self {
+        Self {
+            length: 0,
+            items: [MaybeUninit::uninit(); N],
+        }
+    }
+}
+
+impl<T, const N: usize> Heap<T, N>
+where
+    T: Copy + Ord,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.length == N
+    }
+
+    pub fn clear(&mut self) {
+        self.length = 0;
+    }
+
+    /// The items in heap order, which is not sorted
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `length` items are always initialized
+        unsafe { std::slice::from_raw_parts(self.items.as_ptr().cast(), self.length) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `length` items are always initialized
+        unsafe { std::slice::from_raw_parts_mut(self.items.as_mut_ptr().cast(), self.length) }
+    }
+
+    /// Adds an item. Panics if the heap is full.
+    pub fn push(&mut self, item: T) {
+        assert!(!self.is_full(), "Heap is full");
+        self.items[self.length] = MaybeUninit::new(item);
+        self.length += 1;
+
+        let items = self.as_mut_slice();
+        let mut i = items.len() - 1;
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if items[i] <= items[parent] {
+                break;
+            }
+            items.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    pub fn push_safe(&mut self, item: T) -> Result<(), ()> {
+        if self.is_full() {
+            return Err(());
+        }
+        self.push(item);
+        Ok(())
+    }
+
+    /// Removes the greatest item
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let items = self.as_mut_slice();
+        let last = items.len() - 1;
+        items.swap(0, last);
+        let top = items[last];
+        self.length -= 1;
+
+        let items = self.as_mut_slice();
+        let mut i = 0;
+        loop {
+            let (l, r) = (2 * i + 1, 2 * i + 2);
+            let mut largest = i;
+            if l < items.len() && items[l] > items[largest] {
+                largest = l;
+            }
+            if r < items.len() && items[r] > items[largest] {
+                largest = r;
+            }
+            if largest == i {
+                break;
+            }
+            items.swap(i, largest);
+            i = largest;
+        }
+
+        Some(top)
+    }
+
+    /// Returns the greatest item without removing it
+    pub fn peek(&self) -> Option<T> {
+        self.as_slice().first().copied()
+    }
+
+    /// Iterates in heap order, which is not sorted
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+}
+
+impl<T, const N: usize> Debug for Heap<T, N>
+where
+    T: Copy + Ord + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Pops the items from greatest to smallest
+pub struct HeapIter<T, const N: usize>
+where
+    T: Copy + Ord,
+{
+    heap: Heap<T, N>,
+}
+
+impl<T, const N: usize> Iterator for HeapIter<T, N>
+where
+    T: Copy + Ord,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.heap.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.heap.len(), Some(self.heap.len()))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for HeapIter<T, N> where T: Copy + Ord {}
+
+impl<T, const N: usize> IntoIterator for Heap<T, N>
+where
+    T: Copy + Ord,
+{
+    type Item = T;
+    type IntoIter = HeapIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        HeapIter { heap: self }
+    }
+}
+
+impl<T, const N: usize> Extend<T> for Heap<T, N>
+where
+    T: Copy + Ord,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for Heap<T, N>
+where
+    T: Copy + Ord,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    use super::Heap;
+
+    #[test]
+    fn test_heap_matches_binary_heap() {
+        let mut heap: Heap<u32, 32> = Heap::new();
+        let mut expected = BinaryHeap::new();
+
+        let mut seed = 3u32;
+        for _ in 0..1000 {
+            seed = (seed * 1103 + 12345) % 65536;
+            if !seed.is_multiple_of(3) && !heap.is_full() {
+                heap.push(seed % 100);
+                expected.push(seed % 100);
+            } else {
+                assert_eq!(heap.pop(), expected.pop());
+            }
+            assert_eq!(heap.peek(), expected.peek().copied());
+            assert_eq!(heap.len(), expected.len());
+        }
+    }
+
+    #[test]
+    fn test_min_heap() {
+        let heap: Heap<Reverse<(usize, char)>, 4> = [(3, 'c'), (1, 'a'), (4, 'd'), (2, 'b')]
+            .into_iter()
+            .map(Reverse)
+            .collect();
+        assert!(heap.is_full());
+
+        let sorted: String = heap.into_iter().map(|Reverse((_, c))| c).collect();
+        assert_eq!(sorted, "abcd");
+
+        let mut heap: Heap<u8, 1> = Heap::new();
+        assert_eq!(heap.push_safe(1), Ok(()));
+        assert_eq!(heap.push_safe(2), Err(()));
+    }
+}
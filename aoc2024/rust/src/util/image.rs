@@ -0,0 +1,239 @@
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use super::{Grid, Rgb};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const MAX_STORED_BLOCK: usize = u16::MAX as usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+
+    /// Picks the format from the extension of `path`
+    pub fn from_path(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => Ok(ImageFormat::Ppm),
+            Some("png") => Ok(ImageFormat::Png),
+            _ => anyhow::bail!("Cannot infer an image format from {path:?}"),
+        }
+    }
+}
+
+impl<T: Debug + Copy> Grid<T> {
+    /// Converts the grid into rows of pixels, drawing every cell as a `scale` by `scale` square
+    fn pixels(&self, scale: usize, color: &impl Fn(&T) -> Rgb) -> Vec<Vec<u8>> {
+        assert!(scale > 0, "scale must be at least 1");
+
+        let mut rows = Vec::with_capacity(self.height * scale);
+        for y in 0..self.height_i {
+            let mut row = Vec::with_capacity(self.width * scale * 3);
+            for x in 0..self.width_i {
+                let (r, g, b) = color(&self[(x, y)]);
+                for _ in 0..scale {
+                    row.extend([r, g, b]);
+                }
+            }
+
+            for _ in 0..scale {
+                rows.push(row.clone());
+            }
+        }
+
+        rows
+    }
+
+    /// Encodes the grid as a binary (P6) PPM image
+    pub fn to_ppm(&self, scale: usize, color: impl Fn(&T) -> Rgb) -> Vec<u8> {
+        let header = format!("P6\n{} {}\n255\n", self.width * scale, self.height * scale);
+        let mut out = header.into_bytes();
+        self.pixels(scale, &color)
+            .into_iter()
+            .for_each(|row| out.extend(row));
+        out
+    }
+
+    /// Encodes the grid as an uncompressed 8-bit RGB PNG image
+    pub fn to_png(&self, scale: usize, color: impl Fn(&T) -> Rgb) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(self.height * scale * (self.width * scale * 3 + 1));
+        for row in self.pixels(scale, &color) {
+            raw.push(0); // filter type: none
+            raw.extend(row);
+        }
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend(((self.width * scale) as u32).to_be_bytes());
+        ihdr.extend(((self.height * scale) as u32).to_be_bytes());
+        ihdr.extend([8, 2, 0, 0, 0]); // bit depth, truecolour, compression, filter, interlace
+
+        let mut out = PNG_SIGNATURE.to_vec();
+        write_chunk(&mut out, b"IHDR", &ihdr);
+        write_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        write_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Encodes the grid in `format`
+    pub fn encode(&self, format: ImageFormat, scale: usize, color: impl Fn(&T) -> Rgb) -> Vec<u8> {
+        match format {
+            ImageFormat::Ppm => self.to_ppm(scale, color),
+            ImageFormat::Png => self.to_png(scale, color),
+        }
+    }
+
+    /// Writes the grid to `path`, using the file extension (`.ppm` or `.png`) to pick the format
+    pub fn save_image<P: AsRef<Path>>(
+        &self,
+        path: P,
+        scale: usize,
+        color: impl Fn(&T) -> Rgb,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let format = ImageFormat::from_path(path)?;
+        std::fs::write(path, self.encode(format, scale, color))?;
+        Ok(())
+    }
+}
+
+/// Writes every frame into `dir` as `{prefix}_{index:05}.{ext}` and returns the written paths.
+/// The zero padded index keeps the files in order for tools like `ffmpeg` or `convert`.
+pub fn save_frames<T: Debug + Copy>(
+    frames: &[Grid<T>],
+    dir: impl AsRef<Path>,
+    prefix: &str,
+    format: ImageFormat,
+    scale: usize,
+    color: impl Fn(&T) -> Rgb,
+) -> Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    std::fs::create_dir_all(dir)?;
+
+    frames
+        .iter()
+        .enumerate()
+        .map(|(i, frame)| {
+            let path = dir.join(format!("{prefix}_{i:05}.{}", format.extension()));
+            std::fs::write(&path, frame.encode(format, scale, &color))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    out.extend(kind);
+    out.extend(data);
+
+    let crc = crc32(kind.iter().chain(data));
+    out.extend(crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream made of uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_STORED_BLOCK).peekable();
+
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none() as u8;
+        let len = block.len() as u16;
+        out.push(is_final);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = u32::MAX;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &x| {
+        let a = (a + x as u32) % MOD;
+        (a, (b + a) % MOD)
+    });
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod test {
+    use super::{adler32, crc32, zlib_stored};
+    use crate::util::Grid;
+
+    fn color(v: &bool) -> (u8, u8, u8) {
+        if *v { (255, 255, 255) } else { (0, 0, 0) }
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_to_ppm() {
+        let mut grid = Grid::make(1, 2, false);
+        grid[(1, 0)] = true;
+
+        let mut expected = b"P6\n4 2\n255\n".to_vec();
+        let row = [0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255];
+        expected.extend(row);
+        expected.extend(row);
+
+        assert_eq!(grid.to_ppm(2, color), expected);
+    }
+
+    #[test]
+    fn test_to_png() {
+        let png = Grid::make(3, 4, true).to_png(1, color);
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 4, 0, 0, 0, 3]);
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+    }
+
+    #[test]
+    fn test_zlib_stored_splits_blocks() {
+        let data = vec![7; 70_000];
+        let stream = zlib_stored(&data);
+
+        // header + two block headers + data + checksum
+        assert_eq!(stream.len(), 2 + 5 * 2 + data.len() + 4);
+        assert_eq!(stream[2], 0, "first block must not be final");
+        assert_eq!(
+            stream[2 + 5 + u16::MAX as usize],
+            1,
+            "last block must be final"
+        );
+    }
+}
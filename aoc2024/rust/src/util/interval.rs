@@ -0,0 +1,407 @@
+use std::fmt::Debug;
+use std::ops::Range;
+
+/// The half-open interval `start..end`
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates the interval `start..start + len`
+    pub const fn with_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub const fn len(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start) as usize
+        }
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub const fn contains(&self, v: i64) -> bool {
+        self.start <= v && v < self.end
+    }
+
+    /// Returns true if the intervals share at least one value
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersect(other).is_empty()
+    }
+
+    /// Returns the values in both intervals, which may be empty
+    pub fn intersect(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub const fn shift(&self, by: i64) -> Self {
+        Self::new(self.start + by, self.end + by)
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(r: Range<i64>) -> Self {
+        Self::new(r.start, r.end)
+    }
+}
+
+impl From<Interval> for Range<i64> {
+    fn from(i: Interval) -> Self {
+        i.start..i.end
+    }
+}
+
+impl Debug for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// Shifts every value inside `source` by `offset`, one piece of a piecewise-linear map such as
+/// the seed-to-soil tables of 2023 day 5
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transform {
+    pub source: Interval,
+    pub offset: i64,
+}
+
+impl Transform {
+    pub const fn new(source: Interval, offset: i64) -> Self {
+        Self { source, offset }
+    }
+
+    /// Creates a transform from the `destination source length` triple used by AoC almanacs
+    pub const fn from_triple(destination: i64, source: i64, len: i64) -> Self {
+        Self::new(Interval::with_len(source, len), destination - source)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint and non-adjacent intervals
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorts the intervals and merges the ones that overlap or touch
+    fn normalize(mut intervals: Vec<Interval>) -> Self {
+        intervals.retain(|i| !i.is_empty());
+        intervals.sort_unstable();
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for i in intervals {
+            match merged.last_mut() {
+                Some(last) if i.start <= last.end => last.end = last.end.max(i.end),
+                _ => merged.push(i),
+            }
+        }
+
+        Self { intervals: merged }
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    /// Total number of values in the set
+    pub fn len(&self) -> usize {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Smallest value in the set
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// Largest value in the set
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|i| i.end - 1)
+    }
+
+    pub fn contains(&self, v: i64) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= v);
+        self.intervals.get(i).is_some_and(|i| i.contains(v))
+    }
+
+    /// Adds every value of `interval` to the set
+    pub fn insert(&mut self, interval: impl Into<Interval>) {
+        let interval = interval.into();
+        if interval.is_empty() {
+            return;
+        }
+
+        let lo = self.intervals.partition_point(|i| i.end < interval.start);
+        let hi = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[lo..hi].iter().fold(interval, |acc, i| {
+            Interval::new(acc.start.min(i.start), acc.end.max(i.end))
+        });
+
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    /// Removes every value of `interval` from the set
+    pub fn remove(&mut self, interval: impl Into<Interval>) {
+        *self = self.difference(&Self::from(interval.into()));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.iter().chain(other.iter()).collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (a, b) = (&self.intervals, &other.intervals);
+        let (mut i, mut j) = (0, 0);
+        let mut out = vec![];
+
+        while i < a.len() && j < b.len() {
+            let both = a[i].intersect(&b[j]);
+            if !both.is_empty() {
+                out.push(both);
+            }
+
+            if a[i].end < b[j].end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { intervals: out }
+    }
+
+    /// Returns the values in `self` that are not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut out = vec![];
+        let mut j = 0;
+
+        for &a in &self.intervals {
+            let mut start = a.start;
+            while j < other.intervals.len() && other.intervals[j].end <= start {
+                j += 1;
+            }
+
+            let mut k = j;
+            while k < other.intervals.len() && other.intervals[k].start < a.end {
+                let b = other.intervals[k];
+                if b.start > start {
+                    out.push(Interval::new(start, b.start));
+                }
+                start = start.max(b.end);
+                k += 1;
+            }
+
+            if start < a.end {
+                out.push(Interval::new(start, a.end));
+            }
+        }
+
+        Self { intervals: out }
+    }
+
+    /// Adds `by` to every value in the set
+    pub fn shift(&self, by: i64) -> Self {
+        Self {
+            intervals: self.intervals.iter().map(|i| i.shift(by)).collect(),
+        }
+    }
+
+    /// Sends every value through the first transform whose source contains it.
+    /// Values not covered by any transform map to themselves.
+    pub fn map(&self, transforms: &[Transform]) -> Self {
+        let mut out = vec![];
+        let mut todo = self.intervals.clone();
+
+        for t in transforms {
+            let mut rest = Vec::with_capacity(todo.len());
+            for i in todo {
+                let hit = i.intersect(&t.source);
+                if hit.is_empty() {
+                    rest.push(i);
+                    continue;
+                }
+
+                out.push(hit.shift(t.offset));
+                rest.push(Interval::new(i.start, hit.start));
+                rest.push(Interval::new(hit.end, i.end));
+            }
+            rest.retain(|i| !i.is_empty());
+            todo = rest;
+        }
+
+        out.extend(todo);
+        Self::normalize(out)
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(i: Interval) -> Self {
+        Self::normalize(vec![i])
+    }
+}
+
+impl From<Range<i64>> for RangeSet {
+    fn from(r: Range<i64>) -> Self {
+        Self::from(Interval::from(r))
+    }
+}
+
+impl<I: Into<Interval>> FromIterator<I> for RangeSet {
+    fn from_iter<T: IntoIterator<Item = I>>(iter: T) -> Self {
+        Self::normalize(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<I: Into<Interval>> Extend<I> for RangeSet {
+    fn extend<T: IntoIterator<Item = I>>(&mut self, iter: T) {
+        for i in iter {
+            self.insert(i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Interval, RangeSet, Transform};
+
+    /// Every subset of `0..UNIVERSE` is checked against a plain bitset
+    const UNIVERSE: i64 = 8;
+
+    fn from_bits(bits: u32) -> RangeSet {
+        (0..UNIVERSE)
+            .filter(|v| bits >> v & 1 == 1)
+            .map(|v| v..v + 1)
+            .collect()
+    }
+
+    fn to_bits(set: &RangeSet) -> u32 {
+        set.iter()
+            .flat_map(|i| i.start..i.end)
+            .fold(0, |acc, v| acc | 1 << v)
+    }
+
+    fn is_normalized(set: &RangeSet) -> bool {
+        let ok = set.iter().all(|i| !i.is_empty());
+        ok && set.intervals().windows(2).all(|w| w[0].end < w[1].start)
+    }
+
+    #[test]
+    fn test_set_algebra_exhaustive() {
+        for a in 0..1 << UNIVERSE {
+            let set_a = from_bits(a);
+            assert!(is_normalized(&set_a));
+            assert_eq!(to_bits(&set_a), a);
+            assert_eq!(set_a.len(), a.count_ones() as usize);
+
+            for b in 0..1 << UNIVERSE {
+                let set_b = from_bits(b);
+                let results = [
+                    (set_a.union(&set_b), a | b),
+                    (set_a.intersection(&set_b), a & b),
+                    (set_a.difference(&set_b), a & !b),
+                ];
+
+                for (set, expected) in results {
+                    assert!(is_normalized(&set), "{set:?}");
+                    assert_eq!(to_bits(&set), expected, "{set_a:?} {set_b:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_insert_remove_exhaustive() {
+        for bits in 0..1 << UNIVERSE {
+            for start in 0..UNIVERSE {
+                for end in start..=UNIVERSE {
+                    let mask = (start..end).fold(0, |acc, v| acc | 1 << v);
+
+                    let mut set = from_bits(bits);
+                    set.insert(start..end);
+                    assert!(is_normalized(&set));
+                    assert_eq!(to_bits(&set), bits | mask);
+
+                    let mut set = from_bits(bits);
+                    set.remove(start..end);
+                    assert!(is_normalized(&set));
+                    assert_eq!(to_bits(&set), bits & !mask);
+
+                    for v in 0..UNIVERSE {
+                        assert_eq!(set.contains(v), (bits & !mask) >> v & 1 == 1);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_shift_and_map_exhaustive() {
+        // sends 0..3 to 5..8 and 5..8 to 0..3, leaving 3 and 4 alone
+        let swap = [
+            Transform::from_triple(5, 0, 3),
+            Transform::from_triple(0, 5, 3),
+        ];
+        let image = |v: i64| match v {
+            0..3 => v + 5,
+            5..8 => v - 5,
+            _ => v,
+        };
+
+        for bits in 0..1 << UNIVERSE {
+            let set = from_bits(bits);
+
+            let shifted = set.shift(3);
+            let expected: RangeSet = set
+                .iter()
+                .flat_map(|i| i.start..i.end)
+                .map(|v| v + 3..v + 4)
+                .collect();
+            assert_eq!(shifted, expected);
+
+            let mapped = set.map(&swap);
+            let expected = (0..UNIVERSE)
+                .filter(|v| bits >> v & 1 == 1)
+                .fold(0, |acc, v| acc | 1 << image(v));
+            assert!(is_normalized(&mapped));
+            assert_eq!(to_bits(&mapped), expected, "{set:?} -> {mapped:?}");
+        }
+    }
+
+    #[test]
+    fn test_seed_ranges() {
+        // the seed ranges and seed-to-soil map from the 2023 day 5 example
+        let seeds = RangeSet::from_iter([Interval::with_len(79, 14), Interval::with_len(55, 13)]);
+        let soil = seeds.map(&[
+            Transform::from_triple(50, 98, 2),
+            Transform::from_triple(52, 50, 48),
+        ]);
+
+        assert_eq!(
+            soil.intervals(),
+            [Interval::new(57, 70), Interval::new(81, 95)]
+        );
+        assert_eq!(soil.min(), Some(57));
+        assert_eq!(soil.max(), Some(94));
+    }
+}
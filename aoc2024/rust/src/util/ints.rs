@@ -0,0 +1,92 @@
+use std::marker::PhantomData;
+
+use num_traits::PrimInt;
+
+/// Iterator over the integers embedded in a string, returned by [ints]
+#[derive(Debug, Clone)]
+pub struct Ints<'a, T> {
+    bytes: &'a [u8],
+    pos: usize,
+    _marker: PhantomData<T>,
+}
+
+/// Finds every integer in `s`, skipping whatever separates them.
+///
+/// For signed `T` a `-` directly before a digit makes the number negative, so `"p=3,-4"` yields
+/// `3, -4`. Unsigned types treat `-` as a separator and yield `3, 4`. Does not allocate.
+pub fn ints<T: PrimInt>(s: &str) -> Ints<'_, T> {
+    Ints {
+        bytes: s.as_bytes(),
+        pos: 0,
+        _marker: PhantomData,
+    }
+}
+
+/// Returns the integers in `s` if there are exactly `N` of them, e.g. `ints_array::<_, 2>("3,4")`
+pub fn ints_array<T: PrimInt, const N: usize>(s: &str) -> Option<[T; N]> {
+    let mut iter = ints(s);
+    let mut values = [T::zero(); N];
+    for v in values.iter_mut() {
+        *v = iter.next()?;
+    }
+    iter.next().is_none().then_some(values)
+}
+
+impl<T: PrimInt> Iterator for Ints<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.bytes;
+        let start = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+
+        let signed = T::min_value() < T::zero();
+        let negative = signed && start > 0 && bytes[start - 1] == b'-';
+        let ten = T::from(10).unwrap();
+
+        let mut value = T::zero();
+        let mut i = start;
+        while let Some(&b) = bytes.get(i).filter(|b| b.is_ascii_digit()) {
+            let digit = T::from(b - b'0').unwrap();
+            // accumulate negative numbers downwards so `T::MIN` does not overflow
+            value = if negative {
+                value * ten - digit
+            } else {
+                value * ten + digit
+            };
+            i += 1;
+        }
+
+        self.pos = i;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ints, ints_array};
+
+    #[test]
+    fn test_ints() {
+        let values: Vec<i32> = ints("p=0,4 v=3,-3").collect();
+        assert_eq!(values, [0, 4, 3, -3]);
+
+        let values: Vec<u32> = ints("p=0,4 v=3,-3").collect();
+        assert_eq!(values, [0, 4, 3, 3]);
+
+        let values: Vec<usize> = ints("190: 10 19\n").collect();
+        assert_eq!(values, [190, 10, 19]);
+
+        assert_eq!(ints::<i8>("-128 127").collect::<Vec<_>>(), [-128, 127]);
+        assert_eq!(ints::<i64>("a--5b").collect::<Vec<_>>(), [-5]);
+        assert_eq!(ints::<u8>("no numbers").count(), 0);
+        assert_eq!(ints::<u8>("").count(), 0);
+    }
+
+    #[test]
+    fn test_ints_array() {
+        assert_eq!(ints_array::<usize, 2>("3   4"), Some([3, 4]));
+        assert_eq!(ints_array::<isize, 4>("p=0,4 v=3,-3"), Some([0, 4, 3, -3]));
+        assert_eq!(ints_array::<isize, 2>("1,2,3"), None);
+        assert_eq!(ints_array::<isize, 2>("1"), None);
+    }
+}
@@ -0,0 +1,255 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::gcd;
+
+/// An exact fraction, always stored in lowest terms with a positive denominator
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    /// Creates `num / den` in lowest terms. Panics if `den` is zero.
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "denominator must not be zero");
+
+        let g = gcd(num, den).max(1) * den.signum();
+        Self {
+            num: num / g,
+            den: den / g,
+        }
+    }
+
+    pub const fn numerator(&self) -> i128 {
+        self.num
+    }
+
+    pub const fn denominator(&self) -> i128 {
+        self.den
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    pub const fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    /// Returns the value if it is a whole number
+    pub const fn to_integer(self) -> Option<i128> {
+        if self.is_integer() {
+            Some(self.num)
+        } else {
+            None
+        }
+    }
+
+    pub fn recip(&self) -> Self {
+        Self::new(self.den, self.num)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(v: i128) -> Self {
+        Self { num: v, den: 1 }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Self) -> Self::Output {
+        Rational::new(
+            self.num * other.den + other.num * self.den,
+            self.den * other.den,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Rational::new(self.num * other.num, self.den * other.den)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Self) -> Self::Output {
+        Rational::new(self.num * other.den, self.den * other.num)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num * other.den).cmp(&(other.num * self.den))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num),
+            den => write!(f, "{}/{den}", self.num),
+        }
+    }
+}
+
+impl Debug for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+/// Solves the 2x2 system `m * [x, y] = rhs` exactly with Cramer's rule.
+/// Returns `None` if the system has no unique solution.
+pub fn cramer_2x2(m: [[i128; 2]; 2], rhs: [i128; 2]) -> Option<[Rational; 2]> {
+    let det = m[0][0] * m[1][1] - m[0][1] * m[1][0];
+    if det == 0 {
+        return None;
+    }
+
+    let x = rhs[0] * m[1][1] - m[0][1] * rhs[1];
+    let y = m[0][0] * rhs[1] - rhs[0] * m[1][0];
+    Some([Rational::new(x, det), Rational::new(y, det)])
+}
+
+/// Solves the square system `m * x = rhs` with Gaussian elimination over exact fractions.
+/// Returns `None` if the matrix is singular.
+pub fn gauss_solve(m: &[Vec<i128>], rhs: &[i128]) -> Option<Vec<Rational>> {
+    let n = rhs.len();
+    assert!(
+        m.len() == n && m.iter().all(|row| row.len() == n),
+        "expected a square {n}x{n} matrix"
+    );
+
+    // augmented matrix [m | rhs]
+    let mut a: Vec<Vec<Rational>> = m
+        .iter()
+        .zip(rhs)
+        .map(|(row, &b)| row.iter().chain([&b]).map(|&v| v.into()).collect())
+        .collect();
+
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| !a[r][col].is_zero())?;
+        a.swap(col, pivot);
+
+        let p = a[col][col];
+        a[col].iter_mut().for_each(|v| *v = *v / p);
+
+        let pivot_row = a[col].clone();
+        for r in (0..n).filter(|&r| r != col) {
+            let factor = a[r][col];
+            if factor.is_zero() {
+                continue;
+            }
+
+            for (v, p) in a[r][col..].iter_mut().zip(&pivot_row[col..]) {
+                *v = *v - factor * *p;
+            }
+        }
+    }
+
+    Some(a.into_iter().map(|row| row[n]).collect())
+}
+
+/// Solves `m * x = rhs` and returns the solution only if it is unique and every component is
+/// a whole number
+pub fn solve_integer(m: &[Vec<i128>], rhs: &[i128]) -> Option<Vec<i128>> {
+    gauss_solve(m, rhs)?
+        .into_iter()
+        .map(Rational::to_integer)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Rational, cramer_2x2, gauss_solve, solve_integer};
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, 4);
+        assert_eq!(half, Rational::new(-1, -2));
+        assert_eq!((half.numerator(), half.denominator()), (1, 2));
+        assert_eq!(Rational::new(3, -6).to_string(), "-1/2");
+
+        let third = Rational::new(1, 3);
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert!(third < half && -half < third);
+
+        assert_eq!((half + half).to_integer(), Some(1));
+        assert_eq!(half.to_integer(), None);
+    }
+
+    #[test]
+    fn test_cramer_2x2() {
+        // the first claw machine from 2024 day 13
+        let [a, b] = cramer_2x2([[94, 22], [34, 67]], [8400, 5400]).unwrap();
+        assert_eq!((a.to_integer(), b.to_integer()), (Some(80), Some(40)));
+
+        let [a, _] = cramer_2x2([[26, 67], [66, 21]], [12748, 12176]).unwrap();
+        assert!(!a.is_integer());
+
+        assert!(cramer_2x2([[1, 2], [2, 4]], [3, 6]).is_none());
+    }
+
+    #[test]
+    fn test_cramer_2x2_large_offsets() {
+        // values that lose precision in f64
+        let offset = 10_000_000_000_000;
+        let [a, b] = cramer_2x2([[26, 67], [66, 21]], [12748 + offset, 12176 + offset]).unwrap();
+        assert_eq!(a.to_integer(), Some(118679050709));
+        assert_eq!(b.to_integer(), Some(103199174542));
+    }
+
+    #[test]
+    fn test_gauss_solve() {
+        let m = vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+        assert_eq!(solve_integer(&m, &[8, -11, -3]), Some(vec![2, 3, -1]));
+
+        let m = vec![vec![0, 2], vec![3, 0]];
+        let x = gauss_solve(&m, &[1, 1]).unwrap();
+        assert_eq!(x, [Rational::new(1, 3), Rational::new(1, 2)]);
+        assert_eq!(solve_integer(&m, &[1, 1]), None);
+
+        let singular = vec![vec![1, 2], vec![2, 4]];
+        assert_eq!(gauss_solve(&singular, &[1, 2]), None);
+    }
+}
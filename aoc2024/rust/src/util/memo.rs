@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// Storage behind a [Memo]
+pub trait MemoCache<K, V>: Default {
+    fn lookup(&self, key: &K) -> Option<&V>;
+    fn store(&mut self, key: K, value: V);
+    fn len(&self) -> usize;
+}
+
+impl<K: Hash + Eq, V> MemoCache<K, V> for HashMap<K, V> {
+    fn lookup(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn store(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+}
+
+/// Dense backing for small integer keys, grown on demand
+impl<V> MemoCache<usize, V> for Vec<Option<V>> {
+    fn lookup(&self, key: &usize) -> Option<&V> {
+        self.get(*key)?.as_ref()
+    }
+
+    fn store(&mut self, key: usize, value: V) {
+        if key >= self.len() {
+            self.resize_with(key + 1, || None);
+        }
+        self[key] = Some(value);
+    }
+
+    fn len(&self) -> usize {
+        self.iter().flatten().count()
+    }
+}
+
+/// Caches the results of a recursive function.
+///
+/// The function receives the memo so it can recurse through it:
+///
+/// ```ignore
+/// fn fib(memo: &mut HashMemo<u64, u64>, n: u64) -> u64 {
+///     if n < 2 { n } else { memo.call(n - 1, fib) + memo.call(n - 2, fib) }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V, C = HashMap<K, V>> {
+    cache: C,
+    #[cfg(debug_assertions)]
+    stats: MemoStats,
+    _marker: std::marker::PhantomData<(K, V)>,
+}
+
+/// A memo backed by a `HashMap`
+pub type HashMemo<K, V> = Memo<K, V, HashMap<K, V>>;
+
+/// A memo backed by a `Vec` indexed by the key
+pub type DenseMemo<V> = Memo<usize, V, Vec<Option<V>>>;
+
+impl<K, V, C: MemoCache<K, V>> Default for Memo<K, V, C> {
+    fn default() -> Self {
+        Self {
+            cache: C::default(),
+            #[cfg(debug_assertions)]
+            stats: MemoStats::default(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<K: Clone, V: Clone, C: MemoCache<K, V>> Memo<K, V, C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached value for `key`, computing it with `f` on a miss
+    pub fn call<F>(&mut self, key: K, f: F) -> V
+    where
+        F: Fn(&mut Self, K) -> V,
+    {
+        if let Some(v) = self.cache.lookup(&key) {
+            #[cfg(debug_assertions)]
+            {
+                self.stats.hits += 1;
+            }
+            return v.clone();
+        }
+
+        #[cfg(debug_assertions)]
+        {
+            self.stats.misses += 1;
+        }
+        let v = f(self, key.clone());
+        self.cache.store(key, v.clone());
+        v
+    }
+
+    /// Returns the cached value for `key` without computing it
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.lookup(key)
+    }
+
+    /// Number of cached values
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Cache hits and misses since the memo was created
+    #[cfg(debug_assertions)]
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+}
+
+/// Cache statistics of a [Memo], only tracked in debug builds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl MemoStats {
+    /// Fraction of lookups answered from the cache
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DenseMemo, HashMemo};
+
+    fn fib(memo: &mut HashMemo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            n
+        } else {
+            memo.call(n - 1, fib) + memo.call(n - 2, fib)
+        }
+    }
+
+    #[test]
+    fn test_hashed() {
+        let mut memo = HashMemo::new();
+        assert_eq!(memo.call(90, fib), 2880067194370816120);
+        assert_eq!(memo.len(), 91);
+        assert_eq!(memo.get(&10), Some(&55));
+        assert_eq!(memo.get(&91), None);
+
+        #[cfg(debug_assertions)]
+        {
+            let stats = memo.stats();
+            assert_eq!((stats.hits, stats.misses), (88, 91));
+            println!("{stats}");
+        }
+    }
+
+    #[test]
+    fn test_dense() {
+        // ways to climb `n` stairs taking 1, 2 or 3 steps at a time
+        fn stairs(memo: &mut DenseMemo<usize>, n: usize) -> usize {
+            match n {
+                0 => 1,
+                n => (1..=3.min(n)).map(|s| memo.call(n - s, stairs)).sum(),
+            }
+        }
+
+        let mut memo = DenseMemo::new();
+        assert_eq!(memo.call(4, stairs), 7);
+        assert_eq!(memo.call(30, stairs), 53798080);
+        assert_eq!(memo.len(), 31);
+        assert!(!memo.is_empty());
+    }
+}
@@ -3,21 +3,76 @@ pub mod dijkstra;
 pub mod macros;
 pub mod point;
 
+mod animate;
 mod aoc;
+mod bit_grid;
+mod const_parse;
+mod counter;
+mod cycle;
+mod digits;
 mod direction;
+mod graph;
 mod grid;
-mod quicksort;
+mod grid3;
+mod hash;
+mod heap;
+mod image;
+mod interval;
+mod ints;
+mod linalg;
+mod memo;
+mod num;
+mod parse_error;
+mod point_n;
+mod polygon;
+mod queue;
+mod render;
+mod scan;
+mod sort;
+mod sparse_grid;
 mod stack;
 mod string_methods;
 mod timing;
 
+pub use animate::{Player, Recorder};
 pub use aoc::{Day, Part, Runner, Solution};
+pub use bit_grid::BitGrid;
+pub use const_parse::{
+    ByteGrid, const_ints, count_byte, count_ints, find_byte, find_marker, grid_dims, line_count,
+    lines, nth_line, parse_int, parse_uint, split, split_trimmed,
+};
+pub use counter::{Counter, group_by_key};
+pub use cycle::{brent, fast_forward, find_cycle, floyd};
+pub use digits::{
+    DigitCount, Digits, concat, digits, from_digits, num_digits, pow10, reverse_digits,
+    split_at_digit, split_in_half,
+};
 pub use direction::Direction;
-pub use grid::{Entry, Grid};
+pub use graph::{CycleError, PartialOrder, topo_sort};
+pub use grid::{Entry, Grid, GridPoint};
+pub use grid3::{Grid3, GridPoint3};
+pub use hash::{FastMap, FastSet, FxBuildHasher, FxHasher, PointMap};
+pub use heap::Heap;
+pub use image::{ImageFormat, save_frames};
+pub use interval::{Interval, RangeSet, Transform};
+pub use ints::{Ints, ints, ints_array};
+pub use linalg::{Rational, cramer_2x2, gauss_solve, solve_integer};
+pub use memo::{DenseMemo, HashMemo, Memo, MemoCache, MemoStats};
+pub use num::{crt, extended_gcd, gcd, lcm, lcm_all, mod_inv, mod_pow};
+pub use parse_error::{ParseError, parse_lines};
 pub use point::Point;
-pub use quicksort::quicksort;
+pub use point_n::{Point3, Point4, PointN};
+pub use polygon::Polygon;
+pub use queue::{Deque, Queue};
+pub use render::{Overlay, Render, Rgb};
+pub use scan::{Field, FromFields, ScanError, scan};
+pub use sort::{
+    RadixKey, introsort, introsort_by, introsort_by_key, radix_sort, radix_sort_by_key, select_nth,
+    select_nth_by,
+};
+pub use sparse_grid::SparseGrid;
 pub use stack::Stack;
-pub use string_methods::StringMethods;
+pub use string_methods::{Blocks, StringMethods};
 pub use timing::colorize_time;
 #[cfg(test)]
 pub use timing::validate;
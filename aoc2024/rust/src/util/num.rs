@@ -0,0 +1,182 @@
+use num_traits::{PrimInt, Signed};
+
+/// Greatest common divisor, always non-negative. `gcd(0, 0)` is `0`.
+pub fn gcd<T: PrimInt>(a: T, b: T) -> T {
+    let (mut a, mut b) = (abs(a), abs(b));
+    while !b.is_zero() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, always non-negative. Returns `0` if either value is `0`.
+pub fn lcm<T: PrimInt>(a: T, b: T) -> T {
+    if a.is_zero() || b.is_zero() {
+        return T::zero();
+    }
+    abs(a / gcd(a, b) * b)
+}
+
+/// Least common multiple of every value, e.g. the step at which several cycles line up again.
+/// Returns `1` for an empty iterator.
+pub fn lcm_all<T: PrimInt, I: IntoIterator<Item = T>>(values: I) -> T {
+    values.into_iter().fold(T::one(), lcm)
+}
+
+/// Extended Euclidean algorithm.
+/// Returns `(g, x, y)` where `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::one(), T::zero());
+    let (mut y0, mut y1) = (T::zero(), T::one());
+
+    while !r1.is_zero() {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0.is_negative() {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// Returns `x` in `0..m` with `a * x ≡ 1 (mod m)`, or `None` if `a` and `m` are not coprime
+pub fn mod_inv<T: PrimInt + Signed>(a: T, m: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(rem_euclid(a, m), m);
+    g.is_one().then(|| rem_euclid(x, m))
+}
+
+/// Computes `base^exp mod m` by repeated squaring.
+/// `(m - 1)^2` must fit in `T`.
+pub fn mod_pow<T: PrimInt>(base: T, mut exp: u64, m: T) -> T {
+    assert!(m > T::zero(), "modulus must be positive");
+
+    let mut base = rem_euclid(base, m);
+    let mut result = T::one() % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Chinese remainder theorem: finds the `x` that satisfies every `x ≡ r (mod m)` congruence.
+///
+/// Returns `(x, lcm)` with `x` in `0..lcm`, where `lcm` is the least common multiple of the moduli.
+/// The moduli do not need to be coprime; returns `None` if the congruences contradict each other.
+/// Only the combined `lcm` needs to fit in `T`, intermediate products are reduced first.
+pub fn crt<T: PrimInt + Signed, I: IntoIterator<Item = (T, T)>>(congruences: I) -> Option<(T, T)> {
+    congruences
+        .into_iter()
+        .try_fold((T::zero(), T::one()), |(r1, m1), (r2, m2)| {
+            let (g, p, _) = extended_gcd(m1, m2);
+            let diff = r2 - r1;
+            if !(diff % g).is_zero() {
+                return None;
+            }
+
+            let q = m2 / g;
+            let k = mod_mul(rem_euclid(diff / g, q), rem_euclid(p, q), q);
+            // r1 < m1 and k < q, so this stays below the combined modulus
+            Some((rem_euclid(r1, m1) + m1 * k, m1 * q))
+        })
+}
+
+/// `a * b mod m` for `a` and `b` in `0..m`, by doubling so nothing larger than `m` is formed
+fn mod_mul<T: PrimInt>(mut a: T, mut b: T, m: T) -> T {
+    let add = |x: T, y: T| if x >= m - y { x - (m - y) } else { x + y };
+    let mut result = T::zero();
+    while b > T::zero() {
+        if !(b & T::one()).is_zero() {
+            result = add(result, a);
+        }
+        a = add(a, a);
+        b = b >> 1;
+    }
+    result
+}
+
+fn abs<T: PrimInt>(v: T) -> T {
+    if v < T::zero() { T::zero() - v } else { v }
+}
+
+fn rem_euclid<T: PrimInt>(v: T, m: T) -> T {
+    let r = v % m;
+    if r < T::zero() { r + m } else { r }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{crt, extended_gcd, gcd, lcm, lcm_all, mod_inv, mod_pow};
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0u8, 7), 7);
+        assert_eq!(gcd(0, 0), 0);
+
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0usize, 6), 0);
+        assert_eq!(lcm_all([101u64, 103]), 10403);
+        assert_eq!(lcm_all([2, 3, 4, 5, 6]), 60);
+        assert_eq!(lcm_all(Vec::<i32>::new()), 1);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (46, 240), (-7, 3), (0, 5), (17, 0), (12, -18)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b), "({a}, {b})");
+            assert_eq!(a * x + b * y, g, "({a}, {b})");
+        }
+    }
+
+    #[test]
+    fn test_mod_inv_pow() {
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(6, 9), None);
+
+        assert_eq!(mod_pow(2u64, 10, 1000), 24);
+        assert_eq!(mod_pow(-2i64, 3, 7), 6);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+
+        // Fermat's little theorem
+        for a in 1..13i64 {
+            assert_eq!(mod_pow(a, 12, 13), 1);
+            assert_eq!(mod_inv(a, 13), Some(mod_pow(a, 11, 13)));
+        }
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt(Vec::<(i64, i64)>::new()), Some((0, 1)));
+
+        // the combined modulus only just fits, but the naive products would not
+        assert_eq!(
+            crt([(1i32, 2), (1_000_000_000, 1_000_000_007)]),
+            Some((2_000_000_007, 2_000_000_014))
+        );
+        let (big, small) = (3_037_000_493i64, 3_037_000_453);
+        let (x, m) = crt([(big - 1, big), (small - 2, small)]).unwrap();
+        assert_eq!(m, big * small);
+        assert_eq!((x % big, x % small), (big - 1, small - 2));
+
+        // a robot pattern that lines up horizontally on step 68 and vertically on step 99
+        let (step, period) = crt([(68i64, 101), (99, 103)]).unwrap();
+        assert_eq!(period, 101 * 103);
+        assert_eq!((step % 101, step % 103), (68, 99));
+    }
+}
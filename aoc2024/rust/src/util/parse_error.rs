@@ -0,0 +1,136 @@
+use std::fmt::{Debug, Display};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use super::ScanError;
+
+/// Why some puzzle input could not be parsed, with the 1-based line and column when known
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Sets the line, unless a more precise one was already recorded
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Sets the column, unless a more precise one was already recorded
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Moves the error down `n` lines, for errors from a block that starts `n` lines into the input
+    pub fn shift_lines(mut self, n: usize) -> Self {
+        self.line = self.line.map(|l| l + n);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            (None, Some(column)) => write!(f, "column {column}: ")?,
+            (None, None) => (),
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Shows the same message as `Display`, so `unwrap` in a solver prints where the input is wrong
+impl Debug for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ScanError> for ParseError {
+    fn from(err: ScanError) -> Self {
+        match err {
+            ScanError::Literal { expected, column } => {
+                Self::new(format!("expected {expected:?}")).at_column(column)
+            }
+            ScanError::Parse {
+                value,
+                column,
+                reason,
+                ..
+            } => Self::new(format!("cannot parse {value:?}: {reason}")).at_column(column),
+            err => Self::new(err.to_string()),
+        }
+    }
+}
+
+impl From<ParseIntError> for ParseError {
+    fn from(err: ParseIntError) -> Self {
+        Self::new(err.to_string())
+    }
+}
+
+/// Parses every line of `s` into a `T`, tagging errors with the line they came from
+pub fn parse_lines<T>(s: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    s.lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|e: T::Err| e.into().at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ParseError, parse_lines};
+    use crate::util::Direction;
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new("invalid character 'x'");
+        assert_eq!(err.to_string(), "invalid character 'x'");
+        assert_eq!(
+            err.clone().at_line(3).to_string(),
+            "line 3: invalid character 'x'"
+        );
+        assert_eq!(
+            err.at_column(7)
+                .at_line(3)
+                .at_column(9)
+                .shift_lines(4)
+                .to_string(),
+            "line 7, column 7: invalid character 'x'"
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let dirs: Vec<Direction> = parse_lines("^\nv\n<").unwrap();
+        assert_eq!(dirs, [Direction::North, Direction::South, Direction::West]);
+
+        let err = parse_lines::<Direction>("^\n?").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: invalid direction '?'");
+
+        let err = parse_lines::<u8>("1\n2\n300").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3: number too large to fit in target type"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
 use super::Direction;
 
@@ -22,18 +22,82 @@ impl Point {
         Point::new(0, 0)
     }
 
-    fn unit_step(&self, d: Direction) -> Self {
-        let Point { x, y } = *self;
+    /// Returns a new point shifted one unit in direction `d`
+    pub fn step(&self, d: Direction) -> Self {
+        *self + d.to_point()
+    }
+
+    // =============== Metrics ===============
+
+    /// Taxicab distance, the number of orthogonal steps between the two points
+    pub fn manhattan(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Chessboard distance, the number of king moves between the two points
+    pub fn chebyshev(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Straight line distance between the two points
+    pub fn euclidean(&self, other: &Self) -> f64 {
+        let d = *self - *other;
+        ((d.x * d.x + d.y * d.y) as f64).sqrt()
+    }
 
-        match d {
-            Direction::North => Point::new(x, y - 1),
-            Direction::South => Point::new(x, y + 1),
-            Direction::West => Point::new(x - 1, y),
-            Direction::East => Point::new(x + 1, y),
-            Direction::NorthEast => Point::new(x + 1, y - 1),
-            Direction::NorthWest => Point::new(x - 1, y - 1),
-            Direction::SouthEast => Point::new(x + 1, y + 1),
-            Direction::SouthWest => Point::new(x - 1, y + 1),
+    // =============== Transformations ===============
+
+    /// Rotates the vector 90 degrees clockwise around the origin (with `y` growing downwards)
+    pub fn rotate_cw(&self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Rotates the vector 90 degrees counter-clockwise around the origin (with `y` growing downwards)
+    pub fn rotate_ccw(&self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+
+    /// Mirrors this point through `pivot`, i.e. the point as far past `pivot` as `self` is before it
+    pub fn reflect(&self, pivot: Self) -> Self {
+        pivot * 2 - *self
+    }
+
+    /// Returns the sign of each component, turning any vector into a single step along it
+    pub fn signum(&self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Wraps this point into `0..bounds.x` and `0..bounds.y`, e.g. for a torus shaped grid
+    pub fn rem_euclid(&self, bounds: Self) -> Self {
+        Point::new(self.x.rem_euclid(bounds.x), self.y.rem_euclid(bounds.y))
+    }
+
+    /// Returns every lattice point on the line from `self` to `other`, both ends included,
+    /// using Bresenham's algorithm
+    pub fn line_to(&self, other: Self) -> Vec<Self> {
+        let dx = self.x.abs_diff(other.x) as isize;
+        let dy = -(self.y.abs_diff(other.y) as isize);
+        let step = (other - *self).signum();
+
+        let mut points = Vec::with_capacity(dx.max(-dy) as usize + 1);
+        let mut p = *self;
+        let mut err = dx + dy;
+
+        loop {
+            points.push(p);
+            if p == other {
+                return points;
+            }
+
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                p.x += step.x;
+            }
+            if e2 <= dx {
+                err += dx;
+                p.y += step.y;
+            }
         }
     }
 
@@ -41,111 +105,90 @@ impl Point {
 
     /// Returns a new point shifted one unit north
     pub fn north(&self) -> Self {
-        self.unit_step(Direction::North)
+        self.step(Direction::North)
     }
 
     /// Returns a new point shifted one unit south
     pub fn south(&self) -> Self {
-        self.unit_step(Direction::South)
+        self.step(Direction::South)
     }
 
     /// Returns a new point shifted one unit east
     pub fn east(&self) -> Self {
-        self.unit_step(Direction::East)
+        self.step(Direction::East)
     }
 
     /// Returns a new point shifted one unit west
     pub fn west(&self) -> Self {
-        self.unit_step(Direction::West)
+        self.step(Direction::West)
     }
 
     /// Returns a new point shifted one unit south-east
     pub fn southeast(&self) -> Self {
-        self.unit_step(Direction::SouthEast)
+        self.step(Direction::SouthEast)
     }
 
     /// Returns a new point shifted one unit south-west
     pub fn southwest(&self) -> Self {
-        self.unit_step(Direction::SouthWest)
+        self.step(Direction::SouthWest)
     }
 
     /// Returns a new point shifted one unit north-east
     pub fn northeast(&self) -> Self {
-        self.unit_step(Direction::NorthEast)
+        self.step(Direction::NorthEast)
     }
 
     /// Returns a new point shifted one unit north-west
     pub fn northwest(&self) -> Self {
-        self.unit_step(Direction::NorthWest)
+        self.step(Direction::NorthWest)
     }
 
     // =============== Mutable ===============
 
-    fn unit_step_mut(&mut self, d: Direction) -> &mut Self {
-        match d {
-            Direction::North => self.y -= 1,
-            Direction::South => self.y += 1,
-            Direction::West => self.x -= 1,
-            Direction::East => self.x += 1,
-            Direction::NorthEast => {
-                self.x += 1;
-                self.y -= 1;
-            }
-            Direction::NorthWest => {
-                self.x -= 1;
-                self.y -= 1;
-            }
-            Direction::SouthEast => {
-                self.x += 1;
-                self.y += 1;
-            }
-            Direction::SouthWest => {
-                self.x -= 1;
-                self.y += 1;
-            }
-        };
-
+    /// Mutates this point to move one unit in direction `d`
+    pub fn step_mut(&mut self, d: Direction) -> &mut Self {
+        *self = self.step(d);
         self
     }
 
     /// Mutates this point to move one unit north
     pub fn north_mut(&mut self) -> &mut Self {
-        self.unit_step_mut(Direction::North)
+        self.step_mut(Direction::North)
     }
 
     /// Mutates this point to move one unit south
     pub fn south_mut(&mut self) -> &mut Self {
-        self.unit_step_mut(Direction::South)
+        self.step_mut(Direction::South)
     }
 
     /// Mutates this point to move one unit east
     pub fn east_mut(&mut self) -> &mut Self {
-        self.unit_step_mut(Direction::East)
+        self.step_mut(Direction::East)
     }
 
     /// Mutates this point to move one unit west
     pub fn west_mut(&mut self) -> &mut Self {
-        self.unit_step_mut(Direction::West)
+        self.step_mut(Direction::West)
     }
 
     /// Mutates this point to move one unit south-east
     pub fn southeast_mut(&mut self) -> &mut Self {
-        self.unit_step_mut(Direction::SouthEast)
+        self.step_mut(Direction::SouthEast)
     }
 
     /// Mutates this point to move one unit south-west
     pub fn southwest_mut(&mut self) -> &mut Self {
-        self.unit_step_mut(Direction::SouthWest)
+        self.step_mut(Direction::SouthWest)
     }
 
     /// Mutates this point to move one unit north-east
     pub fn northeast_mut(&mut self) -> &mut Self {
-        self.unit_step_mut(Direction::NorthEast)
+        self.step_mut(Direction::NorthEast)
     }
 
     /// Mutates this point to move one unit north-west
     pub fn northwest_mut(&mut self) -> &mut Self {
-        self.unit_step_mut(Direction::NorthWest)
+        self.step_mut(Direction::NorthWest)
     }
 
     /// Get the coordinates of all orthoganal neighbors from a given point `p`.
@@ -221,6 +264,44 @@ impl Sub for Point {
     }
 }
 
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, scalar: isize) -> Self::Output {
+        Point::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Div<isize> for Point {
+    type Output = Point;
+
+    fn div(self, scalar: isize) -> Self::Output {
+        Point::new(self.x / scalar, self.y / scalar)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
 impl std::fmt::Display for Point {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "({}, {})", self.x, self.y)
@@ -248,4 +329,56 @@ mod test {
         assert_eq!(p3.x, 6);
         assert_eq!(p3.y, 7);
     }
+
+    #[test]
+    fn test_scalar_ops() {
+        let mut p = Point::new(3, -4);
+        assert_eq!(p * 3, Point::new(9, -12));
+        assert_eq!(p / 2, Point::new(1, -2));
+        assert_eq!(-p, Point::new(-3, 4));
+
+        p += Point::new(1, 1);
+        assert_eq!(p, Point::new(4, -3));
+        p -= Point::new(4, 4);
+        assert_eq!(p, Point::new(0, -7));
+    }
+
+    #[test]
+    fn test_metrics() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.euclidean(&b), 5.0);
+    }
+
+    #[test]
+    fn test_transformations() {
+        let north = Point::new(0, -1);
+        assert_eq!(north.rotate_cw(), Point::new(1, 0));
+        assert_eq!(north.rotate_ccw(), Point::new(-1, 0));
+        assert_eq!(north.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), north);
+
+        assert_eq!(Point::new(1, 1).reflect(Point::new(3, 2)), Point::new(5, 3));
+        assert_eq!(Point::new(-7, 12).signum(), Point::new(-1, 1));
+        assert_eq!(
+            Point::new(-1, 13).rem_euclid(Point::new(11, 7)),
+            Point::new(10, 6)
+        );
+    }
+
+    #[test]
+    fn test_line_to() {
+        let line = Point::new(0, 0).line_to(Point::new(4, 2));
+        let expected = [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)].map(Point::from);
+        assert_eq!(line, expected);
+
+        let line = Point::new(2, 3).line_to(Point::new(2, 0));
+        assert_eq!(line.len(), 4);
+        assert_eq!(line.last(), Some(&Point::new(2, 0)));
+        assert_eq!(
+            Point::new(1, 1).line_to(Point::new(1, 1)),
+            [Point::new(1, 1)]
+        );
+    }
 }
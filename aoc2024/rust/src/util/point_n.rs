@@ -0,0 +1,198 @@
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+/// A point in `D` dimensional space, e.g. `PointN<3>` for voxels or `PointN<4>` for 4D cellular
+/// automata
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PointN<const D: usize>(pub [isize; D]);
+
+pub type Point3 = PointN<3>;
+pub type Point4 = PointN<4>;
+
+impl<const D: usize> PointN<D> {
+    pub const fn new(coords: [isize; D]) -> Self {
+        PointN(coords)
+    }
+
+    pub const fn origin() -> Self {
+        PointN([0; D])
+    }
+
+    /// Sum of the absolute differences along every axis
+    pub fn manhattan(&self, other: &Self) -> usize {
+        (0..D).map(|i| self.0[i].abs_diff(other.0[i])).sum()
+    }
+
+    /// Largest absolute difference along any axis
+    pub fn chebyshev(&self, other: &Self) -> usize {
+        (0..D)
+            .map(|i| self.0[i].abs_diff(other.0[i]))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the `3^D - 1` points that differ by at most one along every axis.
+    ///
+    /// The iterator does not allocate, which keeps it cheap in hot cellular automata loops.
+    pub fn nbors(&self) -> impl Iterator<Item = Self> + '_ {
+        let count = 3usize.pow(D as u32);
+        let center = count / 2;
+
+        (0..count).filter(move |&i| i != center).map(move |mut i| {
+            let mut p = *self;
+            for axis in 0..D {
+                p.0[axis] += (i % 3) as isize - 1;
+                i /= 3;
+            }
+            p
+        })
+    }
+
+    /// Returns the `2 * D` points that differ by exactly one along a single axis
+    pub fn nbors_orthogonal(&self) -> impl Iterator<Item = Self> + '_ {
+        (0..D).flat_map(move |axis| {
+            [-1, 1].map(|delta| {
+                let mut p = *self;
+                p.0[axis] += delta;
+                p
+            })
+        })
+    }
+}
+
+impl Point3 {
+    pub const fn xyz(x: isize, y: isize, z: isize) -> Self {
+        PointN([x, y, z])
+    }
+
+    pub const fn x(&self) -> isize {
+        self.0[0]
+    }
+
+    pub const fn y(&self) -> isize {
+        self.0[1]
+    }
+
+    pub const fn z(&self) -> isize {
+        self.0[2]
+    }
+}
+
+impl<const D: usize> Default for PointN<D> {
+    fn default() -> Self {
+        Self::origin()
+    }
+}
+
+impl<const D: usize> From<[isize; D]> for PointN<D> {
+    fn from(coords: [isize; D]) -> Self {
+        PointN(coords)
+    }
+}
+
+impl From<(isize, isize, isize)> for Point3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Self {
+        Point3::xyz(x, y, z)
+    }
+}
+
+impl<const D: usize> Index<usize> for PointN<D> {
+    type Output = isize;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.0[axis]
+    }
+}
+
+impl<const D: usize> IndexMut<usize> for PointN<D> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.0[axis]
+    }
+}
+
+impl<const D: usize> Add for PointN<D> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        PointN(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl<const D: usize> Sub for PointN<D> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        PointN(std::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<const D: usize> Mul<isize> for PointN<D> {
+    type Output = Self;
+
+    fn mul(self, scalar: isize) -> Self::Output {
+        PointN(self.0.map(|v| v * scalar))
+    }
+}
+
+impl<const D: usize> Neg for PointN<D> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        PointN(self.0.map(|v| -v))
+    }
+}
+
+impl<const D: usize> std::fmt::Display for PointN<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{v}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::{Point3, Point4, PointN};
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point3::xyz(1, 2, 3);
+        let b = Point3::xyz(-1, 5, 0);
+
+        assert_eq!(a + b, Point3::xyz(0, 7, 3));
+        assert_eq!(a - b, Point3::xyz(2, -3, 3));
+        assert_eq!(a * 2, Point3::xyz(2, 4, 6));
+        assert_eq!(-a, Point3::xyz(-1, -2, -3));
+        assert_eq!(a.to_string(), "(1, 2, 3)");
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point4::new([0, 0, 0, 0]);
+        let b = Point4::new([1, -2, 3, -4]);
+
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(a.chebyshev(&b), 4);
+    }
+
+    #[test]
+    fn test_nbors() {
+        let p = Point3::xyz(5, -5, 0);
+        let nbors: HashSet<_> = p.nbors().collect();
+        assert_eq!(nbors.len(), 26);
+        assert!(!nbors.contains(&p));
+        assert!(nbors.iter().all(|n| n.chebyshev(&p) == 1));
+
+        assert_eq!(PointN::<4>::origin().nbors().count(), 80);
+
+        let orthogonal: Vec<_> = p.nbors_orthogonal().collect();
+        assert_eq!(orthogonal.len(), 6);
+        assert!(orthogonal.iter().all(|n| n.manhattan(&p) == 1));
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt::Debug;
+
+use super::{Direction, Grid, Point, gcd};
+
+/// A simple (non self-intersecting) polygon with vertices on the integer lattice.
+///
+/// The vertices may be listed in either winding order and the last vertex connects back to the
+/// first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    /// Builds a polygon by walking from `start`, e.g. the dig plan of a lagoon
+    pub fn from_moves<I>(start: Point, moves: I) -> Self
+    where
+        I: IntoIterator<Item = (Direction, usize)>,
+    {
+        let mut vertices = vec![start];
+        let mut p = start;
+        for (d, n) in moves {
+            p += d.to_point() * n as isize;
+            vertices.push(p);
+        }
+
+        if vertices.len() > 1 && vertices.last() == Some(&start) {
+            vertices.pop();
+        }
+
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Returns every edge as a `(from, to)` pair
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Twice the enclosed area, which is always an integer for lattice polygons (shoelace formula)
+    pub fn double_area(&self) -> usize {
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<isize>()
+            .unsigned_abs()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.double_area() as f64 / 2.0
+    }
+
+    /// Total length of the edges
+    pub fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| a.euclidean(&b)).sum()
+    }
+
+    /// Number of lattice points on the edges
+    pub fn boundary_count(&self) -> usize {
+        self.edges()
+            .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)))
+            .sum()
+    }
+
+    /// Number of lattice points strictly inside the polygon, using Pick's theorem.
+    /// Degenerate polygons without area have no interior.
+    pub fn interior_count(&self) -> usize {
+        (self.double_area() + 2).saturating_sub(self.boundary_count()) / 2
+    }
+
+    /// Number of lattice points inside or on the polygon, e.g. the cells dug out of a lagoon
+    pub fn lattice_count(&self) -> usize {
+        self.interior_count() + self.boundary_count()
+    }
+
+    /// Returns every lattice point on the edges
+    pub fn boundary(&self) -> impl Iterator<Item = Point> + '_ {
+        self.edges().flat_map(|(a, b)| {
+            let d = b - a;
+            let n = gcd(d.x.unsigned_abs(), d.y.unsigned_abs()) as isize;
+            let step = if n == 0 { d } else { d / n };
+            (0..n).map(move |i| a + step * i)
+        })
+    }
+
+    /// Returns true if `p` lies on an edge of the polygon
+    pub fn on_boundary(&self, p: Point) -> bool {
+        self.edges().any(|(a, b)| {
+            let cross = (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+            cross == 0
+                && p.x >= a.x.min(b.x)
+                && p.x <= a.x.max(b.x)
+                && p.y >= a.y.min(b.y)
+                && p.y <= a.y.max(b.y)
+        })
+    }
+
+    /// Returns true if `p` is inside the polygon or on its boundary (even-odd rule)
+    pub fn contains(&self, p: Point) -> bool {
+        if self.on_boundary(p) {
+            return true;
+        }
+
+        self.edges()
+            .filter(|(a, b)| (a.y > p.y) != (b.y > p.y))
+            .filter(|(a, b)| {
+                // does the edge cross the ray going east from `p`?
+                let lhs = (p.x - a.x) * (b.y - a.y);
+                let rhs = (p.y - a.y) * (b.x - a.x);
+                if b.y > a.y { lhs < rhs } else { lhs > rhs }
+            })
+            .count()
+            % 2
+            == 1
+    }
+
+    /// Sets every cell of `grid` inside or on the polygon to `value`, one row at a time.
+    /// Parts of the polygon outside the grid are ignored.
+    pub fn fill<T: Debug + Copy>(&self, grid: &mut Grid<T>, value: T) {
+        let mut xs = Vec::new();
+
+        for y in 0..grid.height_i {
+            xs.clear();
+            xs.extend(
+                self.edges()
+                    .filter(|(a, b)| (a.y <= y) != (b.y <= y))
+                    .map(|(a, b)| {
+                        a.x as f64 + (y - a.y) as f64 * (b.x - a.x) as f64 / (b.y - a.y) as f64
+                    }),
+            );
+            xs.sort_by(f64::total_cmp);
+
+            for span in xs.chunks_exact(2) {
+                let from = (span[0].ceil() as isize).max(0);
+                let to = (span[1].floor() as isize).min(grid.width_i - 1);
+                for x in from..=to {
+                    grid[(x, y)] = value;
+                }
+            }
+        }
+
+        for p in self.boundary() {
+            if let Some(cell) = grid.get_mut(p) {
+                *cell = value;
+            }
+        }
+    }
+}
+
+impl From<Vec<Point>> for Polygon {
+    fn from(vertices: Vec<Point>) -> Self {
+        Self::new(vertices)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Polygon;
+    use crate::util::{Direction, Grid, Point};
+
+    fn polygon(vertices: &[(isize, isize)]) -> Polygon {
+        vertices
+            .iter()
+            .copied()
+            .map(Point::from)
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[test]
+    fn test_square() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+
+        assert_eq!(square.area(), 16.0);
+        assert_eq!(square.perimeter(), 16.0);
+        assert_eq!(square.boundary_count(), 16);
+        assert_eq!(square.interior_count(), 9);
+        assert_eq!(square.lattice_count(), 25);
+        assert_eq!(square.boundary().count(), 16);
+    }
+
+    #[test]
+    fn test_triangle() {
+        let triangle = polygon(&[(0, 0), (4, 0), (0, 3)]);
+
+        assert_eq!(triangle.double_area(), 12);
+        assert_eq!(triangle.perimeter(), 12.0);
+        assert_eq!(triangle.boundary_count(), 8);
+        assert_eq!(triangle.interior_count(), 3);
+        assert!(triangle.contains(Point::new(1, 1)));
+        assert!(triangle.contains(Point::new(2, 0)));
+        assert!(!triangle.contains(Point::new(3, 2)));
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let segment = polygon(&[(0, 0), (4, 0)]);
+        assert_eq!(segment.double_area(), 0);
+        assert_eq!(segment.interior_count(), 0);
+
+        let collinear = polygon(&[(0, 0), (2, 2), (5, 5)]);
+        assert_eq!(collinear.interior_count(), 0);
+        assert!(collinear.contains(Point::new(3, 3)));
+    }
+
+    #[test]
+    fn test_from_moves() {
+        // the dig plan from 2023 day 18
+        let plan = "R 6,D 5,L 2,D 2,R 2,D 2,L 5,U 2,L 1,U 2,R 2,U 3,L 2,U 2";
+        let moves = plan.split(',').map(|m| {
+            let (d, n) = m.split_once(' ').unwrap();
+            (d.parse::<Direction>().unwrap(), n.parse().unwrap())
+        });
+        let lagoon = Polygon::from_moves(Point::origin(), moves);
+
+        assert_eq!(lagoon.vertices().len(), 14);
+        assert_eq!(lagoon.lattice_count(), 62);
+    }
+
+    #[test]
+    fn test_fill_matches_contains() {
+        let shapes = [
+            polygon(&[(1, 1), (8, 2), (6, 8), (2, 6)]),
+            polygon(&[
+                (0, 0),
+                (9, 0),
+                (9, 9),
+                (5, 9),
+                (5, 4),
+                (3, 4),
+                (3, 9),
+                (0, 9),
+            ]),
+            polygon(&[(-3, 4), (5, -2), (12, 7)]),
+        ];
+
+        for shape in shapes {
+            let mut grid = Grid::make(10, 10, false);
+            shape.fill(&mut grid, true);
+
+            for (x, y, filled) in grid.entries() {
+                assert_eq!(
+                    *filled,
+                    shape.contains(Point::new(x, y)),
+                    "{shape:?} ({x}, {y})"
+                );
+            }
+        }
+    }
+}
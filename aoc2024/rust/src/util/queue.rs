@@ -0,0 +1,432 @@
+use std::fmt::Debug;
+use std::mem::MaybeUninit;
+use std::ops::{Index, IndexMut};
+
+/// An allocation-free double-ended queue holding at most `N` items in a ring buffer
+#[derive(Clone, Copy)]
+pub struct Deque<T, const N: usize>
+where
+    T: Copy,
+{
+    head: usize,
+    length: usize,
+    items: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> Default for Deque<T, N>
+where
+    T: Copy,
+{
+    fn default() -> Self {
+        Self {
+            head: 0,
+            length: 0,
+            items: [MaybeUninit::uninit(); N],
+        }
+    }
+}
+
+impl<T, const N: usize> Deque<T, N>
+where
+    T: Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.length == N
+    }
+
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.length = 0;
+    }
+
+    /// Maps a logical index to its slot in the ring buffer
+    fn slot(&self, i: usize) -> usize {
+        (self.head + i) % N
+    }
+
+    /// Panics if the deque is full
+    pub fn push_back(&mut self, item: T) {
+        assert!(!self.is_full(), "Deque is full");
+        let slot = self.slot(self.length);
+        self.items[slot] = MaybeUninit::new(item);
+        self.length += 1;
+    }
+
+    /// Panics if the deque is full
+    pub fn push_front(&mut self, item: T) {
+        assert!(!self.is_full(), "Deque is full");
+        self.head = (self.head + N - 1) % N;
+        self.items[self.head] = MaybeUninit::new(item);
+        self.length += 1;
+    }
+
+    pub fn push_back_safe(&mut self, item: T) -> Result<(), ()> {
+        if self.is_full() {
+            return Err(());
+        }
+        self.push_back(item);
+        Ok(())
+    }
+
+    pub fn push_front_safe(&mut self, item: T) -> Result<(), ()> {
+        if self.is_full() {
+            return Err(());
+        }
+        self.push_front(item);
+        Ok(())
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // SAFETY: the `length` slots starting at `head` are always initialized
+        let item = unsafe { self.items[self.head].assume_init() };
+        self.head = (self.head + 1) % N;
+        self.length -= 1;
+        Some(item)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.length -= 1;
+        // SAFETY: the `length` slots starting at `head` are always initialized
+        Some(unsafe { self.items[self.slot(self.length)].assume_init() })
+    }
+
+    pub fn front(&self) -> Option<T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<T> {
+        self.length.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns the item `i` places from the front
+    pub fn get(&self, i: usize) -> Option<T> {
+        (i < self.length).then(|| self[i])
+    }
+
+    /// Returns the items from front to back as two slices, the same way as `VecDeque`
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let first_len = self.length.min(N - self.head);
+        // SAFETY: both ranges only cover initialized slots
+        unsafe {
+            let ptr = self.items.as_ptr().cast::<T>();
+            (
+                std::slice::from_raw_parts(ptr.add(self.head), first_len),
+                std::slice::from_raw_parts(ptr, self.length - first_len),
+            )
+        }
+    }
+
+    /// Iterates from front to back
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        let (a, b) = self.as_slices();
+        a.iter().chain(b)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Deque<T, N>
+where
+    T: Copy,
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < self.length, "index {index} out of bounds");
+        // SAFETY: the index is inside the initialized part of the buffer
+        unsafe { self.items[self.slot(index)].assume_init_ref() }
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Deque<T, N>
+where
+    T: Copy,
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(index < self.length, "index {index} out of bounds");
+        let slot = self.slot(index);
+        // SAFETY: the index is inside the initialized part of the buffer
+        unsafe { self.items[slot].assume_init_mut() }
+    }
+}
+
+impl<T, const N: usize> Debug for Deque<T, N>
+where
+    T: Copy + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+pub struct DequeIter<T, const N: usize>
+where
+    T: Copy,
+{
+    deque: Deque<T, N>,
+}
+
+impl<T, const N: usize> Iterator for DequeIter<T, N>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.deque.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len(), Some(self.deque.len()))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for DequeIter<T, N>
+where
+    T: Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.deque.pop_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for DequeIter<T, N> where T: Copy {}
+
+impl<T, const N: usize> IntoIterator for Deque<T, N>
+where
+    T: Copy,
+{
+    type Item = T;
+    type IntoIter = DequeIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DequeIter { deque: self }
+    }
+}
+
+impl<T, const N: usize> Extend<T> for Deque<T, N>
+where
+    T: Copy,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for Deque<T, N>
+where
+    T: Copy,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+/// An allocation-free first-in first-out queue holding at most `N` items
+#[derive(Clone, Copy)]
+pub struct Queue<T, const N: usize>
+where
+    T: Copy,
+{
+    inner: Deque<T, N>,
+}
+
+impl<T, const N: usize> Default for Queue<T, N>
+where
+    T: Copy,
+{
+    fn default() -> Self {
+        Self {
+            inner: Deque::default(),
+        }
+    }
+}
+
+impl<T, const N: usize> Queue<T, N>
+where
+    T: Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Adds an item to the back. Panics if the queue is full.
+    pub fn push(&mut self, item: T) {
+        self.inner.push_back(item);
+    }
+
+    pub fn push_safe(&mut self, item: T) -> Result<(), ()> {
+        self.inner.push_back_safe(item)
+    }
+
+    /// Removes the item at the front
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop_front()
+    }
+
+    /// Returns the item `pop` would remove
+    pub fn peek(&self) -> Option<T> {
+        self.inner.front()
+    }
+
+    /// Iterates from front to back
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        self.inner.iter()
+    }
+}
+
+impl<T, const N: usize> Debug for Queue<T, N>
+where
+    T: Copy + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<T, const N: usize> IntoIterator for Queue<T, N>
+where
+    T: Copy,
+{
+    type Item = T;
+    type IntoIter = DequeIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for Queue<T, N>
+where
+    T: Copy,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.inner.extend(iter);
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for Queue<T, N>
+where
+    T: Copy,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            inner: Deque::from_iter(iter),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::VecDeque;
+
+    use super::{Deque, Queue};
+    use crate::util::Point;
+
+    #[test]
+    fn test_deque_matches_vec_deque() {
+        let mut deque: Deque<usize, 5> = Deque::new();
+        let mut expected = VecDeque::new();
+
+        // a fixed pseudo-random mix of operations that wraps around the buffer many times
+        let mut seed = 7usize;
+        for i in 0..1000 {
+            seed = (seed * 31 + 11) % 97;
+            match seed % 4 {
+                0 if !deque.is_full() => {
+                    deque.push_back(i);
+                    expected.push_back(i);
+                }
+                1 if !deque.is_full() => {
+                    deque.push_front(i);
+                    expected.push_front(i);
+                }
+                2 => assert_eq!(deque.pop_front(), expected.pop_front()),
+                _ => assert_eq!(deque.pop_back(), expected.pop_back()),
+            }
+
+            assert_eq!(deque.len(), expected.len());
+            assert_eq!(deque.front(), expected.front().copied());
+            assert_eq!(deque.back(), expected.back().copied());
+            assert!(deque.iter().eq(expected.iter()));
+        }
+    }
+
+    #[test]
+    fn test_deque_full() {
+        let mut deque: Deque<u8, 2> = [1, 2].into_iter().collect();
+        assert_eq!(deque.push_back_safe(3), Err(()));
+        assert_eq!(deque.push_front_safe(0), Err(()));
+
+        deque[0] = 5;
+        assert_eq!(format!("{deque:?}"), "[5, 2]");
+        assert_eq!(deque.into_iter().rev().collect::<Vec<_>>(), [2, 5]);
+    }
+
+    #[test]
+    fn test_queue_bfs() {
+        // breadth first flood fill of a 5x5 box, the frontier never exceeds 16 points
+        let mut queue: Queue<Point, 16> = Queue::new();
+        let mut seen = [[false; 5]; 5];
+        let mut order = vec![];
+
+        queue.push(Point::new(2, 2));
+        seen[2][2] = true;
+        while let Some(p) = queue.pop() {
+            order.push(p);
+            for n in p.nbor4() {
+                if (0..5).contains(&n.x)
+                    && (0..5).contains(&n.y)
+                    && !seen[n.y as usize][n.x as usize]
+                {
+                    seen[n.y as usize][n.x as usize] = true;
+                    queue.push(n);
+                }
+            }
+        }
+
+        assert_eq!(order.len(), 25);
+        assert!(
+            order
+                .windows(2)
+                .all(|w| w[0].manhattan(&Point::new(2, 2)) <= w[1].manhattan(&Point::new(2, 2)))
+        );
+        assert_eq!(queue.peek(), None);
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+use super::{Grid, Point};
+use crate::rgb;
+
+/// An `(r, g, b)` colour where each channel ranges from 0 to 255
+pub type Rgb = (u8, u8, u8);
+
+const DIFF_COLOR: Rgb = (255, 0, 0);
+
+/// Describes how a single grid cell is drawn in the terminal
+pub trait Render {
+    /// The character drawn for the cell
+    fn glyph(&self) -> char;
+
+    /// The foreground colour of the cell. `None` draws the glyph with the terminal's default colour.
+    fn color(&self) -> Option<Rgb> {
+        None
+    }
+}
+
+impl Render for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+}
+
+impl Render for u8 {
+    fn glyph(&self) -> char {
+        *self as char
+    }
+}
+
+impl Render for bool {
+    fn glyph(&self) -> char {
+        if *self { '#' } else { '.' }
+    }
+}
+
+impl Render for usize {
+    /// Single digit values are drawn as themselves, anything larger as `+`
+    fn glyph(&self) -> char {
+        match *self {
+            n @ 0..10 => char::from_digit(n as u32, 10).unwrap(),
+            _ => '+',
+        }
+    }
+}
+
+/// Paints `glyph` with `color` using ansi escape codes
+fn paint(glyph: char, color: Option<Rgb>) -> String {
+    match color {
+        Some((r, g, b)) => rgb!(glyph, r, g, b),
+        None => glyph.to_string(),
+    }
+}
+
+/// A set of points drawn on top of a rendered grid, e.g. the path returned by `dijkstra::walk`
+#[derive(Debug, Clone)]
+pub struct Overlay {
+    points: HashSet<Point>,
+    glyph: char,
+    color: Option<Rgb>,
+}
+
+impl Overlay {
+    /// Creates an overlay drawing `glyph` at every point in `points`
+    pub fn new<I: IntoIterator<Item = Point>>(points: I, glyph: char) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+            glyph,
+            color: None,
+        }
+    }
+
+    /// Sets the colour used when drawing the overlay
+    pub fn color(mut self, color: Rgb) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.points.contains(&p)
+    }
+}
+
+impl<T: Debug + Copy + Render> Grid<T> {
+    /// Renders the grid one glyph per cell, one line per row
+    pub fn render(&self) -> String {
+        self.render_with(&[])
+    }
+
+    /// Renders the grid with each overlay drawn on top of the cells.
+    /// Later overlays are drawn over earlier ones.
+    pub fn render_with(&self, overlays: &[Overlay]) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+
+        for y in 0..self.height_i {
+            out.push_str(&self.render_row(y, overlays, |_| false));
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Renders `self` and `other` side by side.
+    /// Cells that differ between the two grids are highlighted in both.
+    pub fn render_diff(&self, other: &Self) -> String {
+        let height = self.height_i.max(other.height_i);
+        let left_pad = " ".repeat(self.width);
+        let differs = |p: Point| self.get(p).map(|v| v.glyph()) != other.get(p).map(|v| v.glyph());
+        let mut out = String::new();
+
+        for y in 0..height {
+            if y < self.height_i {
+                out.push_str(&self.render_row(y, &[], differs));
+            } else {
+                out.push_str(&left_pad);
+            }
+
+            out.push_str(" | ");
+
+            if y < other.height_i {
+                out.push_str(&other.render_row(y, &[], differs));
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+
+    fn render_row(
+        &self,
+        y: isize,
+        overlays: &[Overlay],
+        highlight: impl Fn(Point) -> bool,
+    ) -> String {
+        (0..self.width_i)
+            .map(|x| {
+                let p = Point::new(x, y);
+                let cell = &self[p];
+                let (glyph, color) = overlays
+                    .iter()
+                    .rev()
+                    .find(|o| o.contains(p))
+                    .map_or((cell.glyph(), cell.color()), |o| (o.glyph, o.color));
+
+                match highlight(p) {
+                    true => paint(glyph, Some(DIFF_COLOR)),
+                    false => paint(glyph, color),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Overlay;
+    use crate::rgb;
+    use crate::util::{Grid, Point};
+
+    #[test]
+    fn test_render() {
+        let grid: Grid<char> = Grid::from_str("#..\n.#.\n..#").unwrap();
+        assert_eq!(grid.render(), "#..\n.#.\n..#\n");
+        assert_eq!(grid.to_string(), grid.render());
+    }
+
+    #[test]
+    fn test_render_with_overlay() {
+        let grid = Grid::make(2, 3, false);
+        let path = Overlay::new([Point::new(0, 0), Point::new(1, 0), Point::new(1, 1)], 'o');
+        let end = Overlay::new([Point::new(1, 1)], 'E');
+
+        assert_eq!(grid.render_with(&[path, end]), "oo.\n.E.\n");
+    }
+
+    #[test]
+    fn test_render_diff() {
+        let a: Grid<char> = Grid::from_str("ab\ncd").unwrap();
+        let b: Grid<char> = Grid::from_str("ab\nxd").unwrap();
+        let expected = format!(
+            "ab | ab\n{}d | {}d\n",
+            rgb!('c', 255, 0, 0),
+            rgb!('x', 255, 0, 0)
+        );
+
+        assert_eq!(a.render_diff(&b), expected);
+    }
+}
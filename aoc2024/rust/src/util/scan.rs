@@ -0,0 +1,233 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Why [scan] could not match its pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanError {
+    /// The literal text of the pattern was not found at `column`
+    Literal { expected: String, column: usize },
+    /// Field `field` (counting from 0) could not be parsed
+    Parse {
+        field: usize,
+        value: String,
+        column: usize,
+        reason: String,
+    },
+    /// The pattern has a different number of fields than the requested output
+    FieldCount { expected: usize, found: usize },
+    /// The pattern has two `{}` in a row, which makes the split ambiguous
+    AdjacentFields,
+}
+
+impl Display for ScanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Literal { expected, column } => {
+                write!(f, "expected {expected:?} at column {column}")
+            }
+            Self::Parse {
+                field,
+                value,
+                column,
+                reason,
+            } => write!(
+                f,
+                "cannot parse field {field} {value:?} at column {column}: {reason}"
+            ),
+            Self::FieldCount { expected, found } => {
+                write!(f, "expected {expected} fields but the pattern has {found}")
+            }
+            Self::AdjacentFields => write!(f, "pattern has two adjacent fields"),
+        }
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+/// A piece of the input captured by a `{}` in the pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field<'a> {
+    pub text: &'a str,
+    /// 1-based column of the first character
+    pub column: usize,
+}
+
+impl Field<'_> {
+    /// Parses the text of the field, the `index`th one of the pattern
+    pub fn parse<T>(&self, index: usize) -> Result<T, ScanError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text.parse().map_err(|e: T::Err| ScanError::Parse {
+            field: index,
+            value: self.text.to_string(),
+            column: self.column,
+            reason: e.to_string(),
+        })
+    }
+}
+
+/// Matches `input` against `pattern`, where every `{}` captures text up to the next literal part
+/// of the pattern. A `{}` at the end of the pattern captures the rest of the input.
+///
+/// `scan("p={},{}", "p=3,-4")` returns the fields `"3"` and `"-4"`. See [crate::scan] for
+/// parsing the fields into typed values.
+pub fn scan<'a>(pattern: &str, input: &'a str) -> Result<Vec<Field<'a>>, ScanError> {
+    if pattern.contains("{}{}") {
+        return Err(ScanError::AdjacentFields);
+    }
+
+    let mut literals = pattern.split("{}");
+    let mut rest = input;
+    // count characters rather than bytes so columns match what an editor shows
+    let column = |rest: &str| input[..input.len() - rest.len()].chars().count() + 1;
+
+    let first = literals.next().unwrap_or_default();
+    rest = rest.strip_prefix(first).ok_or_else(|| ScanError::Literal {
+        expected: first.to_string(),
+        column: 1,
+    })?;
+
+    let mut fields = vec![];
+    for literal in literals {
+        let start = column(rest);
+        let text = if literal.is_empty() {
+            // only the last literal can be empty
+            std::mem::take(&mut rest)
+        } else {
+            let (text, after) = rest.split_once(literal).ok_or_else(|| ScanError::Literal {
+                expected: literal.to_string(),
+                column: start,
+            })?;
+            rest = after;
+            text
+        };
+        fields.push(Field {
+            text,
+            column: start,
+        });
+    }
+
+    if !rest.is_empty() {
+        return Err(ScanError::Literal {
+            expected: "end of input".to_string(),
+            column: column(rest),
+        });
+    }
+
+    Ok(fields)
+}
+
+/// Values that can be built from the fields captured by [scan]
+pub trait FromFields: Sized {
+    fn from_fields(fields: &[Field]) -> Result<Self, ScanError>;
+}
+
+fn check_count(fields: &[Field], expected: usize) -> Result<(), ScanError> {
+    if fields.len() == expected {
+        Ok(())
+    } else {
+        Err(ScanError::FieldCount {
+            expected,
+            found: fields.len(),
+        })
+    }
+}
+
+macro_rules! impl_from_fields {
+    ($n: expr; $($t: ident $i: tt),+) => {
+        impl<$($t),+> FromFields for ($($t,)+)
+        where
+            $($t: FromStr, $t::Err: Display),+
+        {
+            fn from_fields(fields: &[Field]) -> Result<Self, ScanError> {
+                check_count(fields, $n)?;
+                Ok(($(fields[$i].parse::<$t>($i)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(1; A 0);
+impl_from_fields!(2; A 0, B 1);
+impl_from_fields!(3; A 0, B 1, C 2);
+impl_from_fields!(4; A 0, B 1, C 2, D 3);
+impl_from_fields!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_fields!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+impl<T, const N: usize> FromFields for [T; N]
+where
+    T: FromStr + Copy + Default,
+    T::Err: Display,
+{
+    fn from_fields(fields: &[Field]) -> Result<Self, ScanError> {
+        check_count(fields, N)?;
+        let mut values = [T::default(); N];
+        for (i, (v, f)) in values.iter_mut().zip(fields).enumerate() {
+            *v = f.parse(i)?;
+        }
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ScanError, scan};
+
+    #[test]
+    fn test_scan_fields() {
+        let fields = scan("p={},{} v={},{}", "p=0,4 v=3,-3").unwrap();
+        let texts: Vec<_> = fields.iter().map(|f| f.text).collect();
+        assert_eq!(texts, ["0", "4", "3", "-3"]);
+        assert_eq!(fields[2].column, 9);
+
+        let fields = scan("{}: {}", "190: 10 19").unwrap();
+        assert_eq!(fields[1].text, "10 19");
+
+        let fields = scan("{}→{}", "αβ→γ").unwrap();
+        assert_eq!((fields[1].text, fields[1].column), ("γ", 4));
+    }
+
+    #[test]
+    fn test_scan_macro() {
+        let (label, x, y): (char, usize, usize) =
+            crate::scan!("Button {}: X+{}, Y+{}", "Button B: X+123, Y+4").unwrap();
+        assert_eq!((label, x, y), ('B', 123, 4));
+
+        let robot: [isize; 4] = crate::scan!("p={},{} v={},{}", "p=10,3 v=-1,2").unwrap();
+        assert_eq!(robot, [10, 3, -1, 2]);
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let err = scan("p={},{}", "q=1,2").unwrap_err();
+        assert_eq!(
+            err,
+            ScanError::Literal {
+                expected: "p=".to_string(),
+                column: 1
+            }
+        );
+
+        let err = crate::scan!("x={}, y={}", "x=1, y=z").map(|(_, y): (u8, u8)| y);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "cannot parse field 1 \"z\" at column 8: invalid digit found in string"
+        );
+
+        assert!(scan("{}!", "hi").is_err());
+        assert!(scan("{}", "hi!").is_ok());
+        assert!(scan("a{}b", "a1b2").is_err());
+        assert_eq!(scan("{}{}", "12"), Err(ScanError::AdjacentFields));
+
+        let err = crate::scan!("{},{}", "1,2").map(|(a,): (u8,)| a);
+        assert_eq!(
+            err,
+            Err(ScanError::FieldCount {
+                expected: 1,
+                found: 2
+            })
+        );
+    }
+}
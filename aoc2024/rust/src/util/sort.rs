@@ -0,0 +1,577 @@
+//! Sorting and selection.
+//!
+//! [introsort] is a pattern-defeating quicksort: it finishes sorted and reversed input in linear
+//! time, groups runs of equal keys, and falls back to heapsort when partitions keep coming out
+//! unbalanced, so it is `O(n log n)` in the worst case and only recurses `O(log n)` deep.
+//! [radix_sort] is an LSD radix sort for integer keys.
+
+use std::cmp::Ordering;
+
+/// Slices this short are insertion sorted
+const INSERTION_THRESHOLD: usize = 20;
+/// Slices this long pick their pivot with Tukey's ninther instead of a median of three
+const NINTHER_THRESHOLD: usize = 128;
+
+pub fn introsort<T: Ord>(v: &mut [T]) {
+    introsort_by(v, T::cmp);
+}
+
+pub fn introsort_by_key<T, K: Ord>(v: &mut [T], mut key: impl FnMut(&T) -> K) {
+    introsort_by(v, |a, b| key(a).cmp(&key(b)));
+}
+
+/// Sorts `v` with `compare`, which must be a total order. Not stable.
+pub fn introsort_by<T>(v: &mut [T], mut compare: impl FnMut(&T, &T) -> Ordering) {
+    let mut is_less = |a: &T, b: &T| compare(a, b) == Ordering::Less;
+    let limit = usize::BITS - v.len().leading_zeros();
+    recurse(v, &mut is_less, None, limit);
+}
+
+/// Reorders `v` so the item at `n` is the one a full sort would put there, with no greater item
+/// before it and no smaller item after it. Runs in linear time on average.
+/// Panics if `n` is out of bounds.
+pub fn select_nth<T: Ord>(v: &mut [T], n: usize) -> &mut T {
+    select_nth_by(v, n, T::cmp)
+}
+
+/// [select_nth] with a custom total order
+pub fn select_nth_by<T>(
+    v: &mut [T],
+    mut n: usize,
+    mut compare: impl FnMut(&T, &T) -> Ordering,
+) -> &mut T {
+    assert!(n < v.len(), "index {n} out of bounds");
+    let is_less = &mut |a: &T, b: &T| compare(a, b) == Ordering::Less;
+    let mut limit = usize::BITS - v.len().leading_zeros();
+
+    // like in `recurse`, `pred` is a pivot from an earlier partition that no item of `v` is below
+    let mut v = v;
+    let mut pred: Option<&T> = None;
+    loop {
+        if v.len() <= INSERTION_THRESHOLD {
+            insertion_sort(v, is_less);
+            return &mut v[n];
+        }
+        if limit == 0 {
+            heapsort(v, is_less);
+            return &mut v[n];
+        }
+
+        let (pivot, _) = choose_pivot(v, is_less);
+        v.swap(0, pivot);
+        if let Some(p) = pred
+            && !is_less(p, &v[0])
+        {
+            // every copy of the smallest value goes to the front in one pass, which keeps
+            // duplicate heavy slices from wasting the partition limit
+            let mid = partition_equal(v, is_less);
+            if n < mid {
+                return &mut v[n];
+            }
+            v = &mut v[mid..];
+            n -= mid;
+            continue;
+        }
+
+        let (mid, _) = partition(v, is_less);
+        if mid.min(v.len() - mid - 1) < v.len() / 8 {
+            limit -= 1;
+        }
+
+        let (left, rest) = v.split_at_mut(mid);
+        let (pivot, right) = rest.split_at_mut(1);
+        match n.cmp(&mid) {
+            Ordering::Equal => return &mut pivot[0],
+            Ordering::Less => v = left,
+            Ordering::Greater => {
+                v = right;
+                n -= mid + 1;
+                pred = Some(&pivot[0]);
+            }
+        }
+    }
+}
+
+/// Sorts `v` by quicksort, where `pred` is the pivot of an enclosing partition that is not
+/// greater than any item of `v` and `limit` is the number of unbalanced partitions left before
+/// switching to heapsort
+fn recurse<'a, T, F>(mut v: &'a mut [T], is_less: &mut F, mut pred: Option<&'a T>, mut limit: u32)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut was_balanced = true;
+    let mut was_partitioned = true;
+
+    loop {
+        let len = v.len();
+        if len <= INSERTION_THRESHOLD {
+            insertion_sort(v, is_less);
+            return;
+        }
+        if limit == 0 {
+            heapsort(v, is_less);
+            return;
+        }
+
+        if !was_balanced {
+            break_patterns(v);
+            limit -= 1;
+        }
+
+        let (pivot, likely_sorted) = choose_pivot(v, is_less);
+        // the last partition was clean and the samples are in order, the slice may already be
+        // sorted apart from a few items
+        if was_balanced && was_partitioned && likely_sorted && partial_insertion_sort(v, is_less) {
+            return;
+        }
+
+        v.swap(0, pivot);
+        if let Some(p) = pred
+            && !is_less(p, &v[0])
+        {
+            // the pivot equals `pred`, so it is the smallest value here and every copy of it can
+            // be set aside at once. This keeps slices with many duplicates linear.
+            let mid = partition_equal(v, is_less);
+            v = &mut v[mid..];
+            continue;
+        }
+
+        let (mid, partitioned) = partition(v, is_less);
+        was_balanced = mid.min(len - mid - 1) >= len / 8;
+        was_partitioned = partitioned;
+
+        let (left, rest) = v.split_at_mut(mid);
+        let (pivot, right) = rest.split_at_mut(1);
+        let pivot = &pivot[0];
+
+        // recurse into the shorter side to bound the stack depth
+        if left.len() < right.len() {
+            recurse(left, is_less, pred, limit);
+            v = right;
+            pred = Some(pivot);
+        } else {
+            recurse(right, is_less, Some(pivot), limit);
+            v = left;
+        }
+    }
+}
+
+/// Partitions around the pivot at `v[0]` and returns its final index, and whether nothing had to
+/// be moved
+fn partition<T, F>(v: &mut [T], is_less: &mut F) -> (usize, bool)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    // `v[1..l]` is less than the pivot and `v[r..]` is not
+    let (mut l, mut r) = (1, v.len());
+    let mut was_partitioned = true;
+    loop {
+        while l < r && is_less(&v[l], &v[0]) {
+            l += 1;
+        }
+        while l < r && !is_less(&v[r - 1], &v[0]) {
+            r -= 1;
+        }
+        if l >= r {
+            break;
+        }
+
+        was_partitioned = false;
+        r -= 1;
+        v.swap(l, r);
+        l += 1;
+    }
+
+    v.swap(0, l - 1);
+    (l - 1, was_partitioned)
+}
+
+/// Moves the items equal to the pivot at `v[0]` to the front, assuming none are less than it,
+/// and returns how many there are
+fn partition_equal<T, F>(v: &mut [T], is_less: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let (mut l, mut r) = (1, v.len());
+    loop {
+        while l < r && !is_less(&v[0], &v[l]) {
+            l += 1;
+        }
+        while l < r && is_less(&v[0], &v[r - 1]) {
+            r -= 1;
+        }
+        if l >= r {
+            return l;
+        }
+
+        r -= 1;
+        v.swap(l, r);
+        l += 1;
+    }
+}
+
+/// Returns the index of a pivot from a median of three or ninther, and whether the samples were
+/// already in order. Reverses `v` if they were all in reverse order.
+fn choose_pivot<T, F>(v: &mut [T], is_less: &mut F) -> (usize, bool)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = v.len();
+    let (mut a, mut b, mut c) = (len / 4, len / 2, len / 4 * 3);
+    let mut swaps = 0;
+
+    let mut sort2 = |a: &mut usize, b: &mut usize, swaps: &mut usize| {
+        if is_less(&v[*b], &v[*a]) {
+            std::mem::swap(a, b);
+            *swaps += 1;
+        }
+    };
+    let mut sort3 = |a: &mut usize, b: &mut usize, c: &mut usize, swaps: &mut usize| {
+        sort2(a, b, swaps);
+        sort2(b, c, swaps);
+        sort2(a, b, swaps);
+    };
+
+    let max_swaps = if len >= NINTHER_THRESHOLD {
+        for x in [&mut a, &mut b, &mut c] {
+            let (mut lo, mut hi) = (*x - 1, *x + 1);
+            sort3(&mut lo, x, &mut hi, &mut swaps);
+        }
+        12
+    } else {
+        3
+    };
+    sort3(&mut a, &mut b, &mut c, &mut swaps);
+
+    if swaps == max_swaps {
+        v.reverse();
+        (len - 1 - b, true)
+    } else {
+        (b, swaps == 0)
+    }
+}
+
+/// Sorts `v` if it only takes a few moves, returning whether it is now sorted
+fn partial_insertion_sort<T, F>(v: &mut [T], is_less: &mut F) -> bool
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    const MAX_STEPS: usize = 5;
+    // shifting items of short slices is not worth it, they are sorted quickly anyway
+    const SHORTEST_SHIFTING: usize = 50;
+
+    let len = v.len();
+    let mut i = 1;
+    for _ in 0..MAX_STEPS {
+        while i < len && !is_less(&v[i], &v[i - 1]) {
+            i += 1;
+        }
+        if i == len {
+            return true;
+        }
+        if len < SHORTEST_SHIFTING {
+            return false;
+        }
+
+        v.swap(i - 1, i);
+        shift_tail(&mut v[..i], is_less);
+        shift_head(&mut v[i..], is_less);
+    }
+    false
+}
+
+/// Moves the last item left until it is in order
+fn shift_tail<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut i = v.len();
+    while i > 1 && is_less(&v[i - 1], &v[i - 2]) {
+        v.swap(i - 1, i - 2);
+        i -= 1;
+    }
+}
+
+/// Moves the first item right until it is in order
+fn shift_head<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut i = 0;
+    while i + 1 < v.len() && is_less(&v[i + 1], &v[i]) {
+        v.swap(i, i + 1);
+        i += 1;
+    }
+}
+
+fn insertion_sort<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    for i in 1..v.len() {
+        shift_tail(&mut v[..=i], is_less);
+    }
+}
+
+fn heapsort<T, F>(v: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let sift_down = |v: &mut [T], mut node: usize, is_less: &mut F| {
+        loop {
+            let mut child = 2 * node + 1;
+            if child >= v.len() {
+                break;
+            }
+            if child + 1 < v.len() && is_less(&v[child], &v[child + 1]) {
+                child += 1;
+            }
+            if !is_less(&v[node], &v[child]) {
+                break;
+            }
+            v.swap(node, child);
+            node = child;
+        }
+    };
+
+    for i in (0..v.len() / 2).rev() {
+        sift_down(v, i, is_less);
+    }
+    for end in (1..v.len()).rev() {
+        v.swap(0, end);
+        sift_down(&mut v[..end], 0, is_less);
+    }
+}
+
+/// Swaps a few items to pseudo-random places, to break up patterns that led to a bad pivot
+fn break_patterns<T>(v: &mut [T]) {
+    let len = v.len();
+    let mut seed = len as u32;
+    let mut random = || {
+        // xorshift32
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as usize
+    };
+
+    let mask = len.next_power_of_two() - 1;
+    let pos = len / 4 * 2;
+    for i in 0..3 {
+        let mut other = random() & mask;
+        if other >= len {
+            other -= len;
+        }
+        v.swap(pos - 1 + i, other);
+    }
+}
+
+/// Integer keys for [radix_sort]
+pub trait RadixKey: Copy {
+    const BYTES: usize;
+
+    /// Maps the key to an unsigned integer with the same order
+    fn radix(self) -> u64;
+}
+
+macro_rules! impl_radix_key {
+    ($($t: ty),+) => {
+        $(impl RadixKey for $t {
+            const BYTES: usize = size_of::<$t>();
+
+            fn radix(self) -> u64 {
+                self as u64
+            }
+        })+
+    };
+    ($($t: ty => $u: ty),+) => {
+        $(impl RadixKey for $t {
+            const BYTES: usize = size_of::<$t>();
+
+            /// Flips the sign bit so negative numbers come first
+            fn radix(self) -> u64 {
+                (self as $u ^ (1 << (<$t>::BITS - 1))) as u64
+            }
+        })+
+    };
+}
+
+impl_radix_key!(u8, u16, u32, u64, usize);
+impl_radix_key!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
+
+pub fn radix_sort<K: RadixKey>(v: &mut [K]) {
+    radix_sort_by_key(v, |k| *k);
+}
+
+/// Sorts `v` by an integer key one byte at a time, skipping bytes that are the same for every
+/// key. Stable, and `O(n)` for a fixed key width, but needs a buffer as large as `v`.
+pub fn radix_sort_by_key<T: Copy, K: RadixKey>(v: &mut [T], key: impl Fn(&T) -> K) {
+    if v.len() < 2 {
+        return;
+    }
+
+    let mut buf = v.to_vec();
+    let mut in_buf = false;
+    for byte in 0..K::BYTES {
+        let digit = |x: &T| (key(x).radix() >> (8 * byte)) as usize & 0xff;
+        let (src, dst) = if in_buf {
+            (&buf[..], &mut v[..])
+        } else {
+            (&v[..], &mut buf[..])
+        };
+
+        let mut counts = [0; 256];
+        for x in src {
+            counts[digit(x)] += 1;
+        }
+        if counts.contains(&src.len()) {
+            continue;
+        }
+
+        let mut offsets = [0; 256];
+        for b in 1..256 {
+            offsets[b] = offsets[b - 1] + counts[b - 1];
+        }
+        for x in src {
+            let d = digit(x);
+            dst[offsets[d]] = *x;
+            offsets[d] += 1;
+        }
+        in_buf = !in_buf;
+    }
+
+    if in_buf {
+        v.copy_from_slice(&buf);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cmp::Reverse;
+
+    use super::{
+        introsort, introsort_by_key, partition, radix_sort, radix_sort_by_key, select_nth,
+        select_nth_by,
+    };
+
+    /// Deterministic inputs covering the patterns that trip up naive quicksorts
+    fn inputs(len: usize) -> Vec<Vec<i64>> {
+        let mut seed = 12345u64;
+        let random: Vec<i64> = (0..len)
+            .map(|_| {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (seed >> 33) as i64 - (1 << 30)
+            })
+            .collect();
+
+        let sorted: Vec<i64> = (0..len as i64).collect();
+        let mut nearly_sorted = sorted.clone();
+        if len > 10 {
+            nearly_sorted.swap(3, len - 4);
+        }
+        let organ_pipe: Vec<i64> = (0..len as i64).map(|i| i.min(len as i64 - i)).collect();
+
+        vec![
+            random.clone(),
+            random.iter().map(|x| x % 4).collect(),
+            sorted.clone(),
+            sorted.iter().rev().copied().collect(),
+            nearly_sorted,
+            organ_pipe,
+            vec![7; len],
+        ]
+    }
+
+    #[test]
+    fn test_partition() {
+        let mut input = [5, 3, 8, 4, 2, 7, 1, 10];
+        let mut is_less = |a: &i32, b: &i32| a < b;
+        let (mid, partitioned) = partition(&mut input, &mut is_less);
+        assert_eq!(input[mid], 5);
+        assert!(input[..mid].iter().all(|&x| x < 5));
+        assert!(input[mid..].iter().all(|&x| x >= 5));
+        assert!(!partitioned);
+
+        let mut input = [3, 1, 2, 3, 4];
+        assert_eq!(partition(&mut input, &mut is_less), (2, true));
+    }
+
+    #[test]
+    fn test_introsort() {
+        let cases: [(Vec<isize>, Vec<isize>); 6] = [
+            (vec![-5, -3, 0, 1, 2, 7, 8], vec![7, -3, 8, 1, 2, -5, 0]),
+            (vec![1, 1, 1, 1], vec![1, 1, 1, 1]),
+            (vec![1, 2, 3, 4, 5], vec![5, 4, 3, 2, 1]),
+            (vec![], vec![]),
+            (vec![42], vec![42]),
+            (vec![-10, -2, 0, 3, 5], vec![0, -2, 5, 3, -10]),
+        ];
+        for (expected, mut input) in cases {
+            introsort(&mut input);
+            assert_eq!(input, expected);
+        }
+
+        for len in [0, 1, 2, 21, 100, 1000, 10_000] {
+            for input in inputs(len) {
+                let mut expected = input.clone();
+                expected.sort();
+
+                let mut v = input.clone();
+                introsort(&mut v);
+                assert_eq!(v, expected, "introsort of {len} items");
+
+                let mut v = input.clone();
+                radix_sort(&mut v);
+                assert_eq!(v, expected, "radix sort of {len} items");
+            }
+        }
+
+        let mut words = ["pear", "fig", "banana", "kiwi"];
+        introsort_by_key(&mut words, |w| Reverse(w.len()));
+        assert_eq!(words[0], "banana");
+    }
+
+    #[test]
+    fn test_radix_sort() {
+        let mut v: Vec<u8> = vec![200, 3, 255, 0, 3];
+        radix_sort(&mut v);
+        assert_eq!(v, [0, 3, 3, 200, 255]);
+
+        let mut v = [i32::MAX, -1, i32::MIN, 0, 1];
+        radix_sort(&mut v);
+        assert_eq!(v, [i32::MIN, -1, 0, 1, i32::MAX]);
+
+        // equal keys keep their order
+        let mut pairs = [(3u16, 'a'), (1, 'b'), (3, 'c'), (1, 'd'), (2, 'e')];
+        radix_sort_by_key(&mut pairs, |p| p.0);
+        let order: String = pairs.iter().map(|p| p.1).collect();
+        assert_eq!(order, "bdeac");
+    }
+
+    #[test]
+    fn test_select_nth() {
+        for input in inputs(1001) {
+            let mut expected = input.clone();
+            expected.sort();
+
+            for n in [0, 1, 500, 999, 1000] {
+                let mut v = input.clone();
+                let nth = *select_nth(&mut v, n);
+                assert_eq!(nth, expected[n]);
+                assert!(v[..n].iter().all(|&x| x <= nth));
+                assert!(v[n + 1..].iter().all(|&x| x >= nth));
+            }
+        }
+
+        let mut v = [1, 5, 2, 4, 3];
+        assert_eq!(*select_nth_by(&mut v, 0, |a, b| b.cmp(a)), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_select_nth_out_of_bounds() {
+        select_nth(&mut [1, 2, 3], 3);
+    }
+}
@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::ops::{Index, IndexMut};
+
+use super::grid::GridPoint;
+use super::{Entry, Grid, Point};
+
+/// A grid with unbounded coordinates that only stores the cells that have been set.
+///
+/// Coordinates can be negative. The bounding box of all stored cells is tracked as cells are
+/// inserted and removed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T: Debug + Copy> {
+    inner: HashMap<Point, T>,
+    min: Point,
+    max: Point,
+}
+
+impl<T: Debug + Copy> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            inner: HashMap::new(),
+            min: Point::origin(),
+            max: Point::origin(),
+        }
+    }
+}
+
+#[allow(unused)]
+impl<T: Debug + Copy> SparseGrid<T> {
+    // ==========================================================
+    // ===================== Static Methods =====================
+    // ==========================================================
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a sparse grid from every entry of `grid` that satisfies `keep`
+    pub fn from_grid<P>(grid: &Grid<T>, mut keep: P) -> Self
+    where
+        P: FnMut(&T) -> bool,
+    {
+        grid.entries()
+            .filter(|(_, _, v)| keep(v))
+            .map(|(x, y, v)| (Point::new(x, y), *v))
+            .collect()
+    }
+
+    // ==========================================================
+    // ===================== Immutable API ======================
+    // ==========================================================
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the inclusive `(min, max)` corners of the bounding box around all stored cells.
+    /// Returns `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        (!self.is_empty()).then_some((self.min, self.max))
+    }
+
+    /// Width of the bounding box, `0` if the grid is empty
+    pub fn width(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| (max.x - min.x + 1) as usize)
+    }
+
+    /// Height of the bounding box, `0` if the grid is empty
+    pub fn height(&self) -> usize {
+        self.bounds()
+            .map_or(0, |(min, max)| (max.y - min.y + 1) as usize)
+    }
+
+    /// Returns true if the point is inside the bounding box, whether or not a value is stored there
+    pub fn inside<P: GridPoint>(&self, p: P) -> bool {
+        let p = p.to_point();
+        self.bounds()
+            .is_some_and(|(min, max)| p.x >= min.x && p.x <= max.x && p.y >= min.y && p.y <= max.y)
+    }
+
+    /// Returns true if a value is stored at `p`
+    pub fn contains<P: GridPoint>(&self, p: P) -> bool {
+        self.inner.contains_key(&p.to_point())
+    }
+
+    pub fn get<P: GridPoint>(&self, p: P) -> Option<&T> {
+        self.inner.get(&p.to_point())
+    }
+
+    /// Returns an iterator over the stored entries in no particular order
+    pub fn entries(&self) -> impl Iterator<Item = Entry<&T>> + '_ {
+        self.inner.iter().map(|(p, v)| (p.x, p.y, v))
+    }
+
+    /// Returns an iterator over the points that hold a value in no particular order
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.inner.keys().copied()
+    }
+
+    /// Returns the values of the cardinal neighbors around point `p`.
+    /// A neighbor is [None] if no value is stored there.
+    pub fn nbor4_values<P: GridPoint>(&self, p: P) -> [Option<&T>; 4] {
+        p.to_point().nbor4().map(|p| self.get(p))
+    }
+
+    /// Get the `entries` - `(x, y, T)` of all cardinal neighbors around point `p`.
+    /// A neighbor is [None] if no value is stored there.
+    /// Order of the list starts at `N` and rotates clockwise.
+    pub fn nbor4<P: GridPoint>(&self, p: P) -> [Option<Entry<T>>; 4] {
+        p.to_point()
+            .nbor4()
+            .map(|p| self.get(p).map(|v| (p.x, p.y, *v)))
+    }
+
+    /// Returns the values of the cardinal and intercardinal neighbors around point `p`.
+    /// A neighbor is [None] if no value is stored there.
+    pub fn nbor8_values<P: GridPoint>(&self, p: P) -> [Option<&T>; 8] {
+        p.to_point().nbor8().map(|p| self.get(p))
+    }
+
+    /// Get the entries of all cardinal and intercardinal neighbors around point `p`.
+    /// A neighbor is [None] if no value is stored there.
+    /// Order of the list starts at `N` and rotates clockwise.
+    pub fn nbor8<P: GridPoint>(&self, p: P) -> [Option<Entry<&T>>; 8] {
+        p.to_point()
+            .nbor8()
+            .map(|p| self.get(p).map(|v| (p.x, p.y, v)))
+    }
+
+    /// Copies the bounding box into a dense `Grid`, filling the cells without a value with `fill`.
+    ///
+    /// The dense grid starts at `(0, 0)`, so a sparse point `p` ends up at `p - min` where `min`
+    /// is the first corner returned by [SparseGrid::bounds].
+    pub fn to_grid(&self, fill: T) -> Grid<T> {
+        let Some((min, _)) = self.bounds() else {
+            return Grid::new(0, 0);
+        };
+
+        let mut grid = Grid::make(self.height(), self.width(), fill);
+        for (p, v) in &self.inner {
+            grid[*p - min] = *v;
+        }
+
+        grid
+    }
+
+    // ==========================================================
+    // ===================== Grid Mutations =====================
+    // ==========================================================
+
+    pub fn get_mut<P: GridPoint>(&mut self, p: P) -> Option<&mut T> {
+        self.inner.get_mut(&p.to_point())
+    }
+
+    /// Stores `value` at `p`, growing the bounding box if needed.
+    /// Returns the value previously stored at `p`.
+    pub fn insert<P: GridPoint>(&mut self, p: P, value: T) -> Option<T> {
+        let p = p.to_point();
+
+        if self.is_empty() {
+            self.min = p;
+            self.max = p;
+        } else {
+            self.min = Point::new(self.min.x.min(p.x), self.min.y.min(p.y));
+            self.max = Point::new(self.max.x.max(p.x), self.max.y.max(p.y));
+        }
+
+        self.inner.insert(p, value)
+    }
+
+    /// Removes and returns the value at `p`, shrinking the bounding box if `p` was on its edge
+    pub fn remove<P: GridPoint>(&mut self, p: P) -> Option<T> {
+        let p = p.to_point();
+        let value = self.inner.remove(&p)?;
+
+        let on_edge =
+            p.x == self.min.x || p.x == self.max.x || p.y == self.min.y || p.y == self.max.y;
+        if on_edge {
+            self.recompute_bounds();
+        }
+
+        Some(value)
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.min = Point::origin();
+        self.max = Point::origin();
+    }
+
+    /// Keeps only the entries that satisfy `keep`
+    pub fn retain<P>(&mut self, mut keep: P)
+    where
+        P: FnMut(Entry<&T>) -> bool,
+    {
+        self.inner.retain(|p, v| keep((p.x, p.y, v)));
+        self.recompute_bounds();
+    }
+
+    fn recompute_bounds(&mut self) {
+        let mut points = self.inner.keys();
+        let Some(&first) = points.next() else {
+            self.clear();
+            return;
+        };
+
+        let (min, max) = points.fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+
+        self.min = min;
+        self.max = max;
+    }
+}
+
+impl<T: Debug + Copy> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T: Debug + Copy> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (p, v) in iter {
+            self.insert(p, v);
+        }
+    }
+}
+
+impl<T: Debug + Copy> From<&Grid<T>> for SparseGrid<T> {
+    fn from(grid: &Grid<T>) -> Self {
+        Self::from_grid(grid, |_| true)
+    }
+}
+
+impl<T: Debug + Copy, P: GridPoint> Index<P> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, p: P) -> &Self::Output {
+        self.get(p)
+            .unwrap_or_else(|| panic!("No value stored at {p:?}"))
+    }
+}
+
+impl<T: Debug + Copy, P: GridPoint> IndexMut<P> for SparseGrid<T> {
+    fn index_mut(&mut self, p: P) -> &mut Self::Output {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("No value stored at {p:?}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::SparseGrid;
+    use crate::util::{Grid, Point};
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert((2, 3), 'a');
+        grid.insert((-4, 1), 'b');
+        grid.insert((0, -2), 'c');
+
+        assert_eq!(grid.bounds(), Some((Point::new(-4, -2), Point::new(2, 3))));
+        assert_eq!((grid.width(), grid.height()), (7, 6));
+        assert!(grid.inside((0, 0)));
+        assert!(!grid.contains((0, 0)));
+
+        grid.remove((-4, 1));
+        assert_eq!(grid.bounds(), Some((Point::new(0, -2), Point::new(2, 3))));
+
+        grid.retain(|(_, _, v)| *v == 'a');
+        assert_eq!(grid.bounds(), Some((Point::new(2, 3), Point::new(2, 3))));
+
+        grid.remove((2, 3));
+        assert_eq!(grid.bounds(), None);
+    }
+
+    #[test]
+    fn test_nbor4() {
+        let grid: SparseGrid<_> = [(Point::new(0, -1), 1), (Point::new(-1, 0), 2)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            grid.nbor4((0, 0)),
+            [Some((0, -1, 1)), None, None, Some((-1, 0, 2))]
+        );
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let dense: Grid<char> = Grid::from_str("..#\n#..\n.#.").unwrap();
+        let sparse = SparseGrid::from_grid(&dense, |c| *c == '#');
+
+        assert_eq!(sparse.len(), 3);
+        assert_eq!(sparse[(2, 0)], '#');
+        assert_eq!(sparse.to_grid('.'), dense);
+    }
+
+    #[test]
+    fn test_to_grid_offsets_negative_points() {
+        let grid: SparseGrid<_> = [(Point::new(-1, -1), true), (Point::new(1, 0), true)]
+            .into_iter()
+            .collect();
+
+        let expected = Grid::from(vec![vec![true, false, false], vec![false, false, true]]);
+        assert_eq!(grid.to_grid(false), expected);
+    }
+}
@@ -1,29 +1,38 @@
+use std::mem::MaybeUninit;
 use std::ops::{Index, IndexMut};
 
+/// An allocation-free stack holding at most `N` items
 #[allow(unused)]
-#[derive(Debug)]
+#[derive(Clone, Copy)]
 pub struct Stack<T, const N: usize>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     length: usize,
-    items: [T; N],
-    capacity: usize,
+    items: [MaybeUninit<T>; N],
 }
 
 impl<T, const N: usize> Default for Stack<T, N>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     fn default() -> Self {
         Self {
-            length: Default::default(),
-            items: [T::default(); N],
-            capacity: Default::default(),
+            length: 0,
+            items: [MaybeUninit::uninit(); N],
         }
     }
 }
 
+impl<T, const N: usize> std::fmt::Debug for Stack<T, N>
+where
+    T: Copy + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
 // #[test]
 // fn foo() {
 //     let slice: &[usize] = &vec![1, 2, 3];
@@ -32,7 +41,7 @@ where
 #[allow(unused)]
 impl<T, const N: usize> Stack<T, N>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     pub fn new() -> Self {
         Self::default()
@@ -43,32 +52,31 @@ where
     }
 
     pub const fn from_slice(slice: &[T]) -> Self {
-        if slice.len() > N || slice.is_empty() {
+        if slice.len() > N {
             panic!("slice length must be smaller than Stacks `N`");
         }
 
-        let mut items = [slice[0]; N];
+        let mut items = [MaybeUninit::uninit(); N];
         let mut i = 0;
 
         while i < slice.len() {
-            items[i] = slice[i];
+            items[i] = MaybeUninit::new(slice[i]);
             i += 1;
         }
 
         Self {
-            capacity: N,
             length: slice.len(),
             items,
         }
     }
 
     pub fn push(&mut self, item: T) {
-        self.items[self.length] = item;
+        self.items[self.length] = MaybeUninit::new(item);
         self.length += 1;
     }
 
     pub fn push_safe(&mut self, item: T) -> Result<(), ()> {
-        if self.length == self.capacity {
+        if self.length == N {
             return Err(());
         }
         self.push(item);
@@ -80,15 +88,26 @@ where
             return None;
         }
         self.length -= 1;
-        Some(self.items[self.length])
+        // SAFETY: slot `length` was below the old length, so `push` initialized it
+        Some(unsafe { self.items[self.length].assume_init() })
     }
 
     pub fn is_empty(&self) -> bool {
         self.length == 0
     }
 
+    pub fn is_full(&self) -> bool {
+        self.length == N
+    }
+
     pub fn as_slice(&self) -> &[T] {
-        unsafe { std::slice::from_raw_parts(self.items.as_ptr(), self.length) }
+        // SAFETY: the first `length` items are always initialized
+        unsafe { std::slice::from_raw_parts(self.items.as_ptr().cast(), self.length) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `length` items are always initialized
+        unsafe { std::slice::from_raw_parts_mut(self.items.as_mut_ptr().cast(), self.length) }
     }
 
     pub fn clear(&mut self) {
@@ -96,35 +115,31 @@ where
     }
 
     pub fn get(&self, i: usize) -> Option<T> {
-        if (i >= self.length) {
-            return None;
-        }
-        Some(self.items[i])
+        self.as_slice().get(i).copied()
     }
 
+    /// Overwrites every slot with `value`, leaving the length unchanged
     pub fn fill(&mut self, value: T) {
-        self.items.fill(value);
+        self.items.fill(MaybeUninit::new(value));
     }
 
     pub fn iter(&self) -> std::slice::Iter<'_, T> {
-        self.items[..self.length].iter()
+        self.as_slice().iter()
     }
 
     /// Returns an iterator over mutable references to the elements.
     pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
-        let len = self.length;
-        self.items[..len].iter_mut()
+        self.as_mut_slice().iter_mut()
     }
 }
 
 impl<T, const N: usize> From<[T; N]> for Stack<T, N>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     fn from(array: [T; N]) -> Self {
         Self {
-            items: array,
-            capacity: N,
+            items: array.map(MaybeUninit::new),
             length: N,
         }
     }
@@ -132,27 +147,27 @@ where
 
 impl<T, const N: usize> Index<usize> for Stack<T, N>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
-        &self.items[index]
+        &self.as_slice()[index]
     }
 }
 
 impl<T, const N: usize> IndexMut<usize> for Stack<T, N>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.items[index]
+        &mut self.as_mut_slice()[index]
     }
 }
 
 impl<'a, T, const N: usize> IntoIterator for &'a Stack<T, N>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     type Item = &'a T;
     type IntoIter = std::slice::Iter<'a, T>;
@@ -164,7 +179,7 @@ where
 
 impl<'a, T, const N: usize> IntoIterator for &'a mut Stack<T, N>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     type Item = &'a mut T;
     type IntoIter = std::slice::IterMut<'a, T>;
@@ -176,7 +191,7 @@ where
 
 pub struct StackIter<T, const N: usize>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     stack: Stack<T, N>,
     front: usize,
@@ -184,13 +199,13 @@ where
 
 impl<T, const N: usize> Iterator for StackIter<T, N>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.front < self.stack.length {
-            let item = self.stack.items[self.front];
+            let item = self.stack[self.front];
             self.front += 1;
             Some(item)
         } else {
@@ -206,23 +221,22 @@ where
 
 impl<T, const N: usize> DoubleEndedIterator for StackIter<T, N>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.front < self.stack.length {
-            self.stack.length -= 1; // Consume from the back
-            Some(self.stack.items[self.stack.length])
+            self.stack.pop() // Consume from the back
         } else {
             None
         }
     }
 }
 
-impl<T, const N: usize> ExactSizeIterator for StackIter<T, N> where T: Copy + Default {}
+impl<T, const N: usize> ExactSizeIterator for StackIter<T, N> where T: Copy {}
 
 impl<T, const N: usize> IntoIterator for Stack<T, N>
 where
-    T: Copy + Default,
+    T: Copy,
 {
     type Item = T;
     type IntoIter = StackIter<T, N>;
@@ -234,3 +248,49 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::Stack;
+
+    /// Deliberately not `Default`
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Id(u8);
+
+    #[test]
+    fn test_stack() {
+        let mut stack: Stack<Id, 3> = Stack::new();
+        assert!(stack.is_empty());
+        stack.push(Id(1));
+        stack.push(Id(2));
+        assert_eq!(stack.as_slice(), [Id(1), Id(2)]);
+        assert_eq!(stack.pop(), Some(Id(2)));
+        assert_eq!(stack.get(1), None);
+
+        assert_eq!(stack.push_safe(Id(3)), Ok(()));
+        assert_eq!(stack.push_safe(Id(4)), Ok(()));
+        assert!(stack.is_full());
+        assert_eq!(stack.push_safe(Id(5)), Err(()));
+        assert_eq!(format!("{stack:?}"), "[Id(1), Id(3), Id(4)]");
+
+        let reversed: Vec<_> = stack.into_iter().rev().collect();
+        assert_eq!(reversed, [Id(4), Id(3), Id(1)]);
+    }
+
+    #[test]
+    fn test_from_slice() {
+        const STACK: Stack<u8, 4> = Stack::from_slice(&[1, 2, 3]);
+        assert_eq!(STACK.as_slice(), [1, 2, 3]);
+        assert!(Stack::<u8, 4>::from_slice(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut stack: Stack<u8, 4> = Stack::from_slice(&[1, 2]);
+        stack.fill(7);
+        assert_eq!(stack.as_slice(), [7, 7]);
+
+        stack.push(1);
+        assert_eq!(stack.as_slice(), [7, 7, 1]);
+    }
+}
@@ -1,4 +1,6 @@
-use std::str::FromStr;
+use num_traits::PrimInt;
+
+use super::ints;
 
 #[allow(unused)]
 pub trait StringMethods {
@@ -9,9 +11,58 @@ pub trait StringMethods {
     fn pad(&self, n: usize, c: char) -> String;
     fn into_padded(s: &str) -> String;
     fn is_not_empty(&self) -> bool;
-    fn to_row<F: FromStr>(&self) -> Vec<F>
-    where
-        <F as FromStr>::Err: std::fmt::Debug;
+    fn to_row<T: PrimInt>(&self) -> Vec<T>;
+    fn blocks(&self) -> Blocks<'_>;
+    fn transpose(&self) -> Vec<String>;
+    fn columns(&self, widths: &[usize]) -> Vec<Vec<&str>>;
+}
+
+/// Iterator over the blocks of lines separated by blank lines, returned by
+/// [StringMethods::blocks]
+#[derive(Debug, Clone)]
+pub struct Blocks<'a> {
+    rest: &'a str,
+    /// 1-based line number of the start of `rest`
+    line: usize,
+}
+
+impl<'a> Blocks<'a> {
+    /// Pairs every block with the 1-based line it starts on
+    pub fn numbered(mut self) -> impl Iterator<Item = (usize, &'a str)> {
+        std::iter::from_fn(move || self.next_numbered())
+    }
+
+    fn next_numbered(&mut self) -> Option<(usize, &'a str)> {
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+        for line in self.rest.split_inclusive('\n') {
+            // a line of only whitespace also ends a block, even if it is `"\r\n"`
+            let content = line.trim_end();
+            if content.trim_start().is_empty() {
+                if start.is_some() {
+                    break;
+                }
+            } else {
+                start.get_or_insert((self.line, offset));
+                end = offset + content.len();
+            }
+            offset += line.len();
+            self.line += 1;
+        }
+
+        let rest = self.rest;
+        self.rest = &rest[offset..];
+        start.map(|(line, start)| (line, &rest[start..end]))
+    }
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_numbered().map(|(_, block)| block)
+    }
 }
 
 impl StringMethods for String {
@@ -55,21 +106,25 @@ impl StringMethods for String {
         self.pad_start(n, ch).pad_end(n, ch)
     }
 
-    /// Trims whitespace, splits the string at `pat`, filters out empty entries, and parses via
-    /// `FromStr`
-    fn to_row<F: FromStr>(&self) -> Vec<F>
-    where
-        <F as FromStr>::Err: std::fmt::Debug,
-    {
-        self.trim()
-            .split("")
-            .filter(|s| !s.trim().is_empty())
-            .map(|s| s.to_string().parse().expect("Failed to parse"))
-            .collect()
+    /// Parses every integer in the string, whatever separates them
+    fn to_row<T: PrimInt>(&self) -> Vec<T> {
+        self.as_str().to_row()
+    }
+
+    fn blocks(&self) -> Blocks<'_> {
+        self.as_str().blocks()
+    }
+
+    fn transpose(&self) -> Vec<String> {
+        self.as_str().transpose()
+    }
+
+    fn columns(&self, widths: &[usize]) -> Vec<Vec<&str>> {
+        self.as_str().columns(widths)
     }
 }
 
-impl StringMethods for &str {
+impl StringMethods for str {
     fn into_padded(s: &str) -> String {
         s.pad(4, '.')
     }
@@ -98,11 +153,49 @@ impl StringMethods for &str {
         self.to_string().pad(n, c)
     }
 
-    fn to_row<F: FromStr>(&self) -> Vec<F>
-    where
-        <F as FromStr>::Err: std::fmt::Debug,
-    {
-        self.to_string().to_row()
+    fn to_row<T: PrimInt>(&self) -> Vec<T> {
+        ints(self).collect()
+    }
+
+    /// Splits on blank lines, accepting `\r\n` and ignoring trailing whitespace, so
+    /// `"a\r\nb\r\n\r\nc\n\n"` has the blocks `"a\r\nb"` and `"c"`
+    fn blocks(&self) -> Blocks<'_> {
+        Blocks {
+            rest: self,
+            line: 1,
+        }
+    }
+
+    /// Turns the columns of the text into rows, padding short lines with spaces
+    fn transpose(&self) -> Vec<String> {
+        let rows: Vec<Vec<char>> = self.lines().map(|l| l.chars().collect()).collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        (0..width)
+            .map(|x| {
+                rows.iter()
+                    .map(|r| r.get(x).copied().unwrap_or(' '))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Cuts every line into fields `widths` characters wide. Short lines give short or empty
+    /// fields and anything past the last field is dropped.
+    fn columns(&self, widths: &[usize]) -> Vec<Vec<&str>> {
+        self.lines()
+            .map(|line| {
+                let mut rest = line;
+                widths
+                    .iter()
+                    .map(|&w| {
+                        let end = rest.char_indices().nth(w).map_or(rest.len(), |(i, _)| i);
+                        let (field, tail) = rest.split_at(end);
+                        rest = tail;
+                        field
+                    })
+                    .collect()
+            })
+            .collect()
     }
 }
 
@@ -130,6 +223,48 @@ mod test {
         assert_eq!(expected, result)
     }
 
+    #[test]
+    fn test_to_row() {
+        assert_eq!("7 6 4 2 1".to_row::<u8>(), [7, 6, 4, 2, 1]);
+        assert_eq!("3,-4\n5".to_string().to_row::<i32>(), [3, -4, 5]);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let text = "47|53\r\n97|13\r\n\r\n75,47\r\n";
+        let blocks: Vec<_> = text.blocks().collect();
+        assert_eq!(blocks, ["47|53\r\n97|13", "75,47"]);
+        assert!(blocks[0].lines().eq(["47|53", "97|13"]));
+
+        let text = "\n a\n\n  \t\n\nb  \nc\n\n\n".to_string();
+        let numbered: Vec<_> = text.blocks().numbered().collect();
+        assert_eq!(numbered, [(2, " a"), (6, "b  \nc")]);
+        assert_eq!("".blocks().count(), 0);
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!("abc\r\nde\r\nf".transpose(), ["adf", "be ", "c  "]);
+        assert_eq!("".transpose(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_columns() {
+        let text = "ab  12\ncd  3\ne";
+        assert_eq!(
+            text.columns(&[2, 2, 2]),
+            [
+                vec!["ab", "  ", "12"],
+                vec!["cd", "  ", "3"],
+                vec!["e", "", ""]
+            ]
+        );
+        assert_eq!(
+            "αβ│γ\nab".columns(&[2, 1, 1]),
+            [["αβ", "│", "γ"], ["ab", "", ""]]
+        );
+    }
+
     #[test]
     fn test_to_char_vec() {
         let string = "string".to_string();